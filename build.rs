@@ -0,0 +1,175 @@
+//! Generates the registry of solutions that the main binary runs in-process.
+//!
+//! With the `in-process` feature, every `src/bin/DD.rs` and `src/bin/YYYY_DD.rs` is included as a module of the main binary
+//! and its `SOLUTION` constant (created by the `solution!` macro) is collected into `SOLUTIONS`.
+//! Without it, `SOLUTIONS` is empty, so that a day that does not compile yet does not break the other commands.
+//! Days that use crate-level attributes (e.g. `#![feature(...)]`) can not be included as a module,
+//! these are skipped and keep running as separate binaries.
+//!
+//! It also generates a test for every named example in `data/examples/{day}/`,
+//! which days include with the `example_tests!` macro.
+use std::{env, fs, path::Path};
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let mut days: Vec<(String, String)> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter_map(|path| {
+                    let stem = path.file_stem()?.to_str()?.to_string();
+                    let is_day = path.extension()? == "rs" && is_day_stem(&stem);
+                    let source = fs::read_to_string(&path).ok()?;
+                    (is_day && !has_crate_attributes(&source))
+                        .then(|| (stem, path.to_string_lossy().to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    days.sort();
+
+    // NOTE: day modules are left out of test builds so their tests do not run twice.
+    // They are also left out when profiling, as every day declares its own global allocator.
+    let condition = r#"all(feature = "in-process", not(any(test, feature = "dhat-heap")))"#;
+    let cfg = format!("#[cfg({condition})]");
+
+    let mut generated = String::from("// @generated by build.rs\n\n");
+
+    for (day, path) in &days {
+        generated.push_str(&format!(
+            "{cfg}\n#[allow(dead_code, clippy::all)]\n#[path = {path:?}]\nmod day_{day};\n\n"
+        ));
+    }
+
+    let entries: Vec<String> = days
+        .iter()
+        .map(|(day, _)| format!("day_{day}::SOLUTION"))
+        .collect();
+
+    generated.push_str(&format!(
+        "{cfg}\npub const SOLUTIONS: &[advent_of_code::template::registry::Solution] = &[{}];\n\n",
+        entries.join(", ")
+    ));
+    generated.push_str(&format!(
+        "#[cfg(not({condition}))]\npub const SOLUTIONS: &[advent_of_code::template::registry::Solution] = &[];\n",
+    ));
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("solutions.rs"), generated).unwrap();
+
+    let examples_dir = Path::new(&manifest_dir).join("data").join("examples");
+    let examples_out_dir = Path::new(&out_dir).join("examples");
+    fs::create_dir_all(&examples_out_dir).unwrap();
+
+    // NOTE: cargo scans directories for changes recursively.
+    println!("cargo:rerun-if-changed=data/examples");
+
+    for stem in day_stems(&bin_dir) {
+        let source = fs::read_to_string(bin_dir.join(format!("{stem}.rs"))).unwrap_or_default();
+        let parser = find_parser(&source);
+        let tests = generate_example_tests(&examples_dir.join(&stem), parser.as_deref());
+        fs::write(examples_out_dir.join(format!("{stem}.rs")), tests).unwrap();
+    }
+}
+
+/// The stems of all days in `src/bin`, including the ones that are not run in-process.
+fn day_stems(bin_dir: &Path) -> Vec<String> {
+    fs::read_dir(bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    let path = entry.path();
+                    let stem = path.file_stem()?.to_str()?.to_string();
+                    (path.extension()? == "rs" && is_day_stem(&stem)).then_some(stem)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Generate a test for every part of an example that has an expected answer in its `{name}.answers` file.
+/// Days with a shared parser pass the parsed example to their parts.
+fn generate_example_tests(dir: &Path, parser: Option<&str>) -> String {
+    let mut generated = String::from("// @generated by build.rs\n\n");
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return generated;
+    };
+
+    let mut names: Vec<String> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let path = entry.path();
+            (path.extension()? == "txt").then(|| path.file_stem()?.to_str().map(str::to_string))?
+        })
+        .collect();
+    names.sort();
+
+    for name in names {
+        let answers = fs::read_to_string(dir.join(format!("{name}.answers"))).unwrap_or_default();
+        let ident: String = name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+
+        for (part, func) in [(1, "part_one"), (2, "part_two")] {
+            let has_answer = answers.lines().any(|line| {
+                line.split_once(':')
+                    .is_some_and(|(x, _)| x.trim() == part.to_string())
+            });
+
+            let solve = match parser {
+                Some(parser) => format!("|input: &str| {func}(&{parser}(input))"),
+                None => func.to_string(),
+            };
+
+            if has_answer {
+                generated.push_str(&format!(
+                    "#[test]\nfn example_{ident}_{func}() {{\n    advent_of_code::template::examples::check(DAY, {name:?}, {part}, {solve});\n}}\n\n"
+                ));
+            }
+        }
+    }
+
+    generated
+}
+
+/// Days are named `DD`, or `YYYY_DD` for days of a specific year.
+fn is_day_stem(stem: &str) -> bool {
+    let is_number = |s: &str, len: usize| s.len() == len && s.chars().all(|c| c.is_ascii_digit());
+
+    match stem.split_once('_') {
+        Some((year, day)) => is_number(year, 4) && is_number(day, 2),
+        None => is_number(stem, 2),
+    }
+}
+
+/// The parser a day registers with `solution!(…, parse = parser)`, if any.
+fn find_parser(source: &str) -> Option<String> {
+    let start = source.find("solution!(")? + "solution!(".len();
+    let end = start + source[start..].find(')')?;
+
+    source[start..end].split(',').find_map(|arg| {
+        let (key, value) = arg.split_once('=')?;
+        (key.trim() == "parse").then(|| value.trim().to_string())
+    })
+}
+
+fn has_crate_attributes(source: &str) -> bool {
+    source
+        .lines()
+        .any(|line| line.trim_start().starts_with("#!["))
+}
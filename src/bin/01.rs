@@ -100,15 +100,17 @@ pub fn part_two(input: &str) -> Option<u64> {
 mod tests {
     use super::*;
 
+    advent_of_code::example_tests!();
+
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY).unwrap());
         assert_eq!(result, Some(3));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY).unwrap());
         assert_eq!(result, Some(6));
     }
 }
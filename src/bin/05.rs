@@ -82,16 +82,20 @@ pub fn part_two(input: &str) -> Option<u64> {
 mod tests {
     use super::*;
 
+    advent_of_code::example_tests!();
+
     #[test]
     fn test_parse_input() {
-        let (ranges, ids) = parse_input(&advent_of_code::template::read_file("examples", DAY));
+        let (ranges, ids) =
+            parse_input(&advent_of_code::template::read_file("examples", DAY).unwrap());
         assert_eq!(ranges.len(), 4);
         assert_eq!(ids.len(), 6);
     }
 
     #[test]
     fn test_merge_ranges() {
-        let (ranges, _) = parse_input(&advent_of_code::template::read_file("examples", DAY));
+        let (ranges, _) =
+            parse_input(&advent_of_code::template::read_file("examples", DAY).unwrap());
         let merged = merge_ranges(&ranges);
         assert_eq!(merged, vec![(3, 5), (10, 20)]);
     }
@@ -107,13 +111,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY).unwrap());
         assert_eq!(result, Some(3));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY).unwrap());
         assert_eq!(result, Some(14));
     }
 }
@@ -80,7 +80,7 @@ pub fn collect_sorted_pairs(boxes: &[IVec3]) -> Vec<(usize, usize)> {
         }
     }
 
-    pairs.sort_unstable_by(|(da, _), (db, _)| da.cmp(db)); // ascending distance
+    pairs.sort_unstable_by_key(|(d, _)| *d); // ascending distance
 
     pairs.into_iter().map(|(_, ij)| ij).collect()
 }
@@ -112,15 +112,20 @@ pub fn part_two(input: &str) -> Option<u64> {
 mod tests {
     use super::*;
 
+    advent_of_code::example_tests!();
+
     #[test]
     fn test_part_one() {
-        let result = count_circuits(&advent_of_code::template::read_file("examples", DAY), 10);
+        let result = count_circuits(
+            &advent_of_code::template::read_file("examples", DAY).unwrap(),
+            10,
+        );
         assert_eq!(result, Some(40));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY).unwrap());
         assert_eq!(result, Some(25272));
     }
 }
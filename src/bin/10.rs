@@ -360,14 +360,16 @@ pub fn part_two(input: &str) -> Option<u64> {
 mod tests {
     use super::*;
 
+    advent_of_code::example_tests!();
+
     #[test]
     fn test_parse_input_p1() {
-        let _ = parse_input_p1(&advent_of_code::template::read_file("examples", DAY));
+        let _ = parse_input_p1(&advent_of_code::template::read_file("examples", DAY).unwrap());
     }
 
     #[test]
     fn test_parse_input_p2() {
-        let _ = parse_input_p2(&advent_of_code::template::read_file("examples", DAY));
+        let _ = parse_input_p2(&advent_of_code::template::read_file("examples", DAY).unwrap());
     }
 
     #[test]
@@ -385,13 +387,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY).unwrap());
         assert_eq!(result, Some(7));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", DAY).unwrap());
         assert_eq!(result, Some(33));
     }
 }
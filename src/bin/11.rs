@@ -13,10 +13,10 @@ pub enum DeviceId {
 }
 
 impl DeviceId {
-    pub const YOU: Self = DeviceId::Normal([b'y', b'o', b'u']);
-    pub const DAC: Self = DeviceId::Normal([b'd', b'a', b'c']);
-    pub const FFT: Self = DeviceId::Normal([b'f', b'f', b't']);
-    pub const SVR: Self = DeviceId::Normal([b's', b'v', b'r']);
+    pub const YOU: Self = DeviceId::Normal(*b"you");
+    pub const DAC: Self = DeviceId::Normal(*b"dac");
+    pub const FFT: Self = DeviceId::Normal(*b"fft");
+    pub const SVR: Self = DeviceId::Normal(*b"svr");
 
     pub fn from_hash(hash: [u8; 3]) -> DeviceId {
         match hash {
@@ -154,17 +154,18 @@ pub fn part_two(input: &str) -> Option<u64> {
 mod tests {
     use super::*;
 
+    advent_of_code::example_tests!();
+
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", DAY).unwrap());
         assert_eq!(result, Some(5));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
-        ));
+        let result =
+            part_two(&advent_of_code::template::read_file_part("examples", DAY, 2).unwrap());
         assert_eq!(result, Some(2));
     }
 }
@@ -1,18 +1,32 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::solve;
+use advent_of_code::template::commands::{all, download, read, scaffold, time, verify};
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
+use advent_of_code::template::{Day, last_day_of};
 #[cfg(feature = "today")]
 use std::process;
 
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::commands::{solve::SolveOptions, time::TimeOptions};
+    use advent_of_code::template::{
+        Day, Year,
+        budget::BenchBudget,
+        compare,
+        record::OutputFormat,
+        runner::parse_timeout,
+        solution_options::{parse_input_source, parse_part, with_example_value},
+    };
+    use std::{env, error::Error, process, time::Duration};
 
     pub enum AppArguments {
         Download {
             day: Day,
+            example_block: usize,
         },
         Read {
             day: Day,
@@ -21,58 +35,108 @@ mod args {
             day: Day,
             download: bool,
             overwrite: bool,
+            example_block: usize,
         },
         Solve {
             day: Day,
-            release: bool,
-            dhat: bool,
-            submit: Option<u8>,
+            options: SolveOptions,
+            watch: bool,
         },
         All {
+            year: Option<Year>,
             release: bool,
+            format: OutputFormat,
+            jobs: usize,
+            timeout: Option<Duration>,
         },
         Time {
-            all: bool,
             day: Option<Day>,
-            store: bool,
+            year: Option<Year>,
+            options: TimeOptions,
+        },
+        History {
+            day: Day,
+        },
+        Verify {
+            year: Option<Year>,
+            release: bool,
+            jobs: usize,
+            timeout: Option<Duration>,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
-        let mut args = pico_args::Arguments::from_env();
+    pub fn parse() -> Result<AppArguments, Box<dyn Error>> {
+        let mut args =
+            pico_args::Arguments::from_vec(with_example_value(env::args_os().skip(1).collect()));
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
+                year: opt_year(&mut args)?,
                 release: args.contains("--release"),
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                timeout: args.opt_value_from_fn("--timeout", parse_timeout)?,
             },
             Some("time") => {
-                let all = args.contains("--all");
-                let store = args.contains("--store");
+                if let Some(day) = args.opt_value_from_str("--history")? {
+                    return finish(args, AppArguments::History { day });
+                }
+
+                let threshold = args
+                    .opt_value_from_str("--threshold")?
+                    .unwrap_or(compare::DEFAULT_THRESHOLD);
+                let options = TimeOptions {
+                    run_all: args.contains("--all"),
+                    store: args.contains("--store"),
+                    format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                    compare_threshold: args.contains("--compare").then_some(threshold),
+                    timeout: args.opt_value_from_fn("--timeout", parse_timeout)?,
+                    part: args.opt_value_from_fn("--part", parse_part)?,
+                    budget: BenchBudget::from_args(&mut args)?,
+                };
 
                 AppArguments::Time {
-                    all,
-                    day: args.opt_free_from_str()?,
-                    store,
+                    year: opt_year(&mut args)?,
+                    day: opt_free_day(&mut args)?,
+                    options,
                 }
             }
+            Some("verify") => AppArguments::Verify {
+                year: opt_year(&mut args)?,
+                release: args.contains("--release"),
+                jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                timeout: args.opt_value_from_fn("--timeout", parse_timeout)?,
+            },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                example_block: args.opt_value_from_str("--example-block")?.unwrap_or(1),
+                day: free_day(&mut args)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                day: free_day(&mut args)?,
             },
+            // NOTE: free arguments are parsed last, as the day may span two of them.
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                example_block: args.opt_value_from_str("--example-block")?.unwrap_or(1),
+                day: free_day(&mut args)?,
             },
             Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+                options: SolveOptions {
+                    release: args.contains("--release"),
+                    submit_part: args.opt_value_from_str("--submit")?,
+                    force: args.contains("--force"),
+                    dhat: args.contains("--dhat"),
+                    format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                    timeout: args.opt_value_from_fn("--timeout", parse_timeout)?,
+                    input: parse_input_source(&mut args)?,
+                    part: args.opt_value_from_fn("--part", parse_part)?,
+                    run_tests: args.contains("--test"),
+                },
+                watch: args.contains("--watch"),
+                day: free_day(&mut args)?,
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -86,6 +150,35 @@ mod args {
             }
         };
 
+        finish(args, app_args)
+    }
+
+    /// Parse a day, optionally preceded by its year: `5`, `2024 5` or `2024_05`.
+    fn opt_free_day(args: &mut pico_args::Arguments) -> Result<Option<Day>, Box<dyn Error>> {
+        let Some(first) = args.opt_free_from_str::<String>()? else {
+            return Ok(None);
+        };
+
+        // the day is checked against the event of its year, so parse both together.
+        let day: Day = match args.opt_free_from_str::<String>()? {
+            Some(day) => format!("{first}_{day}").parse()?,
+            None => first.parse()?,
+        };
+        Ok(Some(day.normalized()))
+    }
+
+    fn opt_year(args: &mut pico_args::Arguments) -> Result<Option<Year>, Box<dyn Error>> {
+        Ok(Year::normalize(args.opt_value_from_str("--year")?))
+    }
+
+    fn free_day(args: &mut pico_args::Arguments) -> Result<Day, Box<dyn Error>> {
+        opt_free_day(args)?.ok_or_else(|| "expected a day argument.".into())
+    }
+
+    fn finish(
+        args: pico_args::Arguments,
+        app_args: AppArguments,
+    ) -> Result<AppArguments, Box<dyn Error>> {
         let remaining = args.finish();
         if !remaining.is_empty() {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
@@ -102,38 +195,64 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
+            AppArguments::All {
+                year,
+                release,
+                format,
+                jobs,
+                timeout,
+            } => all::handle(year, release, format, jobs, timeout, solutions::SOLUTIONS),
+            AppArguments::Time { day, year, options } => {
+                time::handle(day, year, options, solutions::SOLUTIONS)
+            }
+            AppArguments::History { day } => time::handle_history(day),
+            AppArguments::Verify {
+                year,
+                release,
+                jobs,
+                timeout,
+            } => verify::handle(year, release, jobs, timeout, solutions::SOLUTIONS),
+            AppArguments::Download { day, example_block } => download::handle(day, example_block),
             AppArguments::Read { day } => read::handle(day),
             AppArguments::Scaffold {
                 day,
                 download,
                 overwrite,
+                example_block,
             } => {
                 scaffold::handle(day, overwrite);
                 if download {
-                    download::handle(day);
+                    download::handle(day, example_block);
                 }
             }
             AppArguments::Solve {
                 day,
-                release,
-                dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
+                options,
+                watch: false,
+            } => solve::handle(day, &options),
+            AppArguments::Solve {
+                day,
+                options,
+                watch: true,
+            } => {
+                if let Err(e) = solve::watch(day, &options) {
+                    eprintln!("Error: {e}");
+                    std::process::exit(1);
+                }
+            }
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
                     Some(day) => {
                         scaffold::handle(day, false);
-                        download::handle(day);
+                        download::handle(day, 1);
                         read::handle(day)
                     }
                     None => {
                         eprintln!(
                             "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            the {}th of december. Please use `scaffold` with a specific day.",
+                            last_day_of(None)
                         );
                         process::exit(1)
                     }
@@ -0,0 +1,84 @@
+/// Module that stores accepted answers, so solutions can be verified against them.
+use std::{fs, io::Error, path::Path};
+
+use crate::template::Day;
+
+static ANSWERS_DIR: &str = "./data/answers";
+
+/// Result of checking an answer against the recorded one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnswerCheck {
+    Correct,
+    Incorrect {
+        expected: String,
+    },
+    /// No answer has been recorded for this part yet.
+    Unknown,
+    /// The part did not report a result, e.g. because it panicked, timed out or its input is missing.
+    Missing {
+        expected: String,
+    },
+}
+
+#[must_use]
+pub fn get_path(day: Day, part: u8) -> String {
+    format!("{ANSWERS_DIR}/{day}-{part}.txt")
+}
+
+/// Read the recorded answer of a part, if present.
+pub fn read(day: Day, part: u8) -> Option<String> {
+    fs::read_to_string(get_path(day, part))
+        .ok()
+        .map(|x| x.trim_end().to_string())
+}
+
+/// Record the accepted answer of a part, overwriting previous answers.
+pub fn store(day: Day, part: u8, answer: &str) -> Result<(), Error> {
+    fs::create_dir_all(Path::new(ANSWERS_DIR))?;
+    fs::write(get_path(day, part), format!("{}\n", answer.trim_end()))
+}
+
+/// Check an answer against the recorded answer of its part, see [`read`].
+pub(crate) fn compare(expected: Option<&str>, answer: Option<&str>) -> AnswerCheck {
+    match (expected, answer) {
+        (None, _) => AnswerCheck::Unknown,
+        (Some(expected), Some(answer)) if expected == answer.trim_end() => AnswerCheck::Correct,
+        (Some(expected), _) => AnswerCheck::Incorrect {
+            expected: expected.to_string(),
+        },
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{AnswerCheck, compare};
+
+    #[test]
+    fn compares_answers() {
+        assert_eq!(compare(Some("42"), Some("42")), AnswerCheck::Correct);
+        assert_eq!(compare(Some("42"), Some("42\n")), AnswerCheck::Correct);
+        assert_eq!(
+            compare(Some("42"), Some("43")),
+            AnswerCheck::Incorrect {
+                expected: "42".into()
+            }
+        );
+        assert_eq!(
+            compare(Some("42"), None),
+            AnswerCheck::Incorrect {
+                expected: "42".into()
+            }
+        );
+        assert_eq!(compare(None, Some("42")), AnswerCheck::Unknown);
+    }
+
+    #[test]
+    fn compares_multi_line_answers() {
+        assert_eq!(
+            compare(Some("#..#\n.##."), Some("#..#\n.##.\n")),
+            AnswerCheck::Correct
+        );
+    }
+}
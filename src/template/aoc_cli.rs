@@ -1,10 +1,15 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
+    env,
+    error::Error,
     fmt::Display,
+    fs, process,
     process::{Command, Output, Stdio},
 };
 
 use crate::template::Day;
+use crate::template::backend::PuzzleBackend;
+use crate::template::submission::Verdict;
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -25,85 +30,94 @@ impl Display for AocCommandError {
     }
 }
 
-pub fn check() -> Result<(), AocCommandError> {
-    Command::new("aoc")
-        .arg("-V")
-        .output()
-        .map_err(|_| AocCommandError::CommandNotFound)?;
-    Ok(())
+impl Error for AocCommandError {}
+
+/// Backend that shells out to "aoc-cli".
+pub struct AocCli;
+
+impl AocCli {
+    /// Check that "aoc-cli" is installed.
+    pub fn new() -> Result<Self, AocCommandError> {
+        Command::new("aoc")
+            .arg("-V")
+            .output()
+            .map_err(|_| AocCommandError::CommandNotFound)?;
+        Ok(Self)
+    }
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+impl PuzzleBackend for AocCli {
+    fn name(&self) -> &'static str {
+        "aoc-cli"
+    }
 
-    let args = build_args(
-        "read",
-        &[
-            "--description-only".into(),
-            "--puzzle-file".into(),
-            puzzle_path,
-        ],
-        day,
-    );
+    fn input(&self, day: Day) -> Result<String, Box<dyn Error>> {
+        download_to_file(day, "input")
+    }
+
+    fn description(&self, day: Day) -> Result<String, Box<dyn Error>> {
+        download_to_file(day, "puzzle")
+    }
+
+    /// Submit an answer and classify the response. Unknown responses are printed as-is.
+    fn submit(&self, day: Day, part: u8, answer: &str) -> Result<Verdict, Box<dyn Error>> {
+        // workaround: the argument order is inverted for submit.
+        let mut args = build_args("submit", &[], day);
+        args.push(part.to_string());
+        args.push(answer.to_string());
+
+        let output = call_aoc_cli_captured(&args)?;
+        let response = String::from_utf8_lossy(&output.stdout).to_string();
 
-    call_aoc_cli(&args)
+        match Verdict::from_response(&response) {
+            Verdict::Unknown if !output.status.success() => {
+                Err(AocCommandError::BadExitStatus(output).into())
+            }
+            Verdict::Unknown => {
+                eprint!("{response}");
+                Ok(Verdict::Unknown)
+            }
+            verdict => Ok(verdict),
+        }
+    }
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
+/// "aoc-cli" only writes puzzle files, so download to a temporary file and read it back.
+/// `kind` is either `input` or `puzzle`.
+fn download_to_file(day: Day, kind: &str) -> Result<String, Box<dyn Error>> {
+    let path = env::temp_dir().join(format!("aoc-cli-{}-{day}-{kind}", process::id()));
 
     let args = build_args(
         "download",
         &[
             "--overwrite".into(),
-            "--input-file".into(),
-            input_path.to_string(),
-            "--puzzle-file".into(),
-            puzzle_path.to_string(),
+            format!("--{kind}-only"),
+            format!("--{kind}-file"),
+            path.to_string_lossy().into(),
         ],
         day,
     );
 
-    let output = call_aoc_cli(&args)?;
-    println!("---");
-    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
-    Ok(output)
-}
-
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
-    // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
-    args.push(part.to_string());
-    args.push(result.to_string());
-    call_aoc_cli(&args)
-}
-
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
-}
-
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
-}
-
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
+    call_aoc_cli(&args)?;
+    let contents = fs::read_to_string(&path)?;
+    fs::remove_file(&path)?;
+    Ok(contents)
 }
 
+/// Build the arguments of an "aoc-cli" command for a day. The year is the one of `day`, or `AOC_YEAR`.
 fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
+    if let Some(year) = day.puzzle_year() {
         cmd_args.push("--year".into());
         cmd_args.push(year.to_string());
     }
 
-    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    // the year is passed separately, only pass the number of the day.
+    cmd_args.push("--day".into());
+    cmd_args.push(day.into_inner().to_string());
+
+    cmd_args.push(command.into());
 
     cmd_args
 }
@@ -123,3 +137,29 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
         Err(AocCommandError::BadExitStatus(output))
     }
 }
+
+/// Like [`call_aoc_cli`], but captures stdout so it can be inspected. The exit status is not checked.
+fn call_aoc_cli_captured(args: &[String]) -> Result<Output, AocCommandError> {
+    Command::new("aoc")
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::build_args;
+    use crate::day;
+
+    #[test]
+    fn builds_args_for_days_of_a_year() {
+        assert_eq!(
+            build_args("download", &["--overwrite".into()], day!(2024, 5)),
+            ["--overwrite", "--year", "2024", "--day", "5", "download"]
+        );
+    }
+}
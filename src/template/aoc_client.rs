@@ -0,0 +1,323 @@
+/// Built-in client for the Advent of Code website, used instead of "aoc-cli" when the
+/// `native-client` feature is enabled and a session cookie is available.
+use std::{env, error::Error, fmt::Display, fs, path::PathBuf, time::Duration};
+
+use crate::template::Day;
+use crate::template::backend::PuzzleBackend;
+use crate::template::submission::Verdict;
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+static DEFAULT_USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (native-client)"
+);
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    YearNotSet,
+    Request(String),
+    BadStatus(u16),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set AOC_SESSION or write it to ~/.adventofcode.session."
+            ),
+            AocClientError::YearNotSet => {
+                write!(f, "AOC_YEAR is not set, and the day does not have a year.")
+            }
+            AocClientError::Request(e) => write!(f, "request failed: {e}"),
+            AocClientError::BadStatus(status) => {
+                write!(f, "server responded with status {status}.")
+            }
+        }
+    }
+}
+
+impl Error for AocClientError {}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        AocClientError::Request(e.to_string())
+    }
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    user_agent: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    /// Configure a client from the environment:
+    ///  - `AOC_SESSION` or `~/.adventofcode.session` for the session cookie.
+    ///  - `AOC_BASE_URL` to talk to a server other than adventofcode.com, e.g. a local stub.
+    ///  - `AOC_USER_AGENT` to identify requests, e.g. with the URL of your repository.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let session = read_session().ok_or(AocClientError::SessionNotFound)?;
+
+        let user_agent = env::var("AOC_USER_AGENT")
+            .ok()
+            .filter(|x| !x.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_USER_AGENT.into());
+
+        let base_url = env::var("AOC_BASE_URL")
+            .unwrap_or_else(|_| DEFAULT_BASE_URL.into())
+            .trim_end_matches('/')
+            .to_string();
+
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Ok(Self {
+            base_url,
+            session,
+            user_agent,
+            agent,
+        })
+    }
+
+    /// The URL of a day's puzzle. Days without a year belong to `AOC_YEAR`.
+    fn day_url(&self, day: Day) -> Result<String, AocClientError> {
+        let year = day.puzzle_year().ok_or(AocClientError::YearNotSet)?;
+        Ok(format!("{}/{year}/day/{}", self.base_url, day.into_inner()))
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        let mut response = self
+            .agent
+            .get(url)
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", &self.user_agent)
+            .call()?;
+
+        let status = response.status().as_u16();
+        if status != 200 {
+            return Err(AocClientError::BadStatus(status));
+        }
+
+        Ok(response.body_mut().read_to_string()?)
+    }
+}
+
+impl PuzzleBackend for AocClient {
+    fn name(&self) -> &'static str {
+        "native client"
+    }
+
+    fn input(&self, day: Day) -> Result<String, Box<dyn Error>> {
+        Ok(self.get(&format!("{}/input", self.day_url(day)?))?)
+    }
+
+    fn description(&self, day: Day) -> Result<String, Box<dyn Error>> {
+        Ok(html_to_markdown(&self.get(&self.day_url(day)?)?))
+    }
+
+    /// Submit an answer and classify the response. Unknown responses are printed as-is.
+    fn submit(&self, day: Day, part: u8, answer: &str) -> Result<Verdict, Box<dyn Error>> {
+        let mut response = self
+            .agent
+            .post(format!("{}/answer", self.day_url(day)?))
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", &self.user_agent)
+            .send_form([("level", part.to_string()), ("answer", answer.to_string())])
+            .map_err(AocClientError::from)?;
+
+        let status = response.status().as_u16();
+        if status != 200 {
+            return Err(AocClientError::BadStatus(status).into());
+        }
+
+        let text = html_to_text(&response.body_mut().read_to_string()?);
+
+        match Verdict::from_response(&text) {
+            Verdict::Unknown => {
+                eprintln!("{text}");
+                Ok(Verdict::Unknown)
+            }
+            verdict => Ok(verdict),
+        }
+    }
+}
+
+fn read_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION")
+        && !session.trim().is_empty()
+    {
+        return Some(session.trim().to_string());
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    let path = PathBuf::from(home).join(".adventofcode.session");
+    let session = fs::read_to_string(path).ok()?.trim().to_string();
+    (!session.is_empty()).then_some(session)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The contents of all `<article>` elements of a page, which hold puzzle descriptions and responses.
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+        let content_start = start + open_end + 1;
+        let Some(end) = rest[content_start..].find("</article>") else {
+            break;
+        };
+        articles.push(&rest[content_start..content_start + end]);
+        rest = &rest[content_start + end..];
+    }
+
+    articles
+}
+
+/// Convert the puzzle descriptions of a page to markdown.
+/// Only handles the small set of tags used on Advent of Code puzzle pages.
+fn html_to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut links: Vec<String> = vec![];
+    let mut in_pre = false;
+
+    for article in articles(html) {
+        let mut rest = article;
+
+        while let Some(tag_start) = rest.find('<') {
+            let text = &rest[..tag_start];
+            // line breaks between block elements are formatting of the page, not content.
+            if in_pre || !(text.trim().is_empty() && text.contains('\n')) {
+                markdown.push_str(&decode_entities(text));
+            }
+
+            let Some(tag_end) = rest[tag_start..].find('>') else {
+                break;
+            };
+            let tag = &rest[tag_start + 1..tag_start + tag_end];
+            rest = &rest[tag_start + tag_end + 1..];
+
+            let name = tag
+                .split(|c: char| c.is_whitespace() || c == '/')
+                .find(|x| !x.is_empty())
+                .unwrap_or_default();
+            let is_closing = tag.starts_with('/');
+
+            match (name, is_closing) {
+                ("h2", false) => markdown.push_str("## "),
+                ("h2" | "p" | "ul", true) => markdown.push_str("\n\n"),
+                ("li", false) => markdown.push_str("- "),
+                ("li", true) => markdown.push('\n'),
+                ("em", _) => markdown.push('*'),
+                ("code", _) if !in_pre => markdown.push('`'),
+                ("pre", false) => {
+                    in_pre = true;
+                    markdown.push_str("```\n");
+                }
+                ("pre", true) => {
+                    in_pre = false;
+                    if !markdown.ends_with('\n') {
+                        markdown.push('\n');
+                    }
+                    markdown.push_str("```\n\n");
+                }
+                ("a", false) => {
+                    links.push(attribute(tag, "href").unwrap_or_default().to_string());
+                    markdown.push('[');
+                }
+                ("a", true) => {
+                    let href = links.pop().unwrap_or_default();
+                    markdown.push_str(&format!("]({href})"));
+                }
+                _ => {}
+            }
+        }
+
+        markdown.push_str(&decode_entities(rest));
+    }
+
+    markdown.trim_end().to_string() + "\n"
+}
+
+/// Convert the articles of a page to plain text.
+fn html_to_text(html: &str) -> String {
+    let mut text = String::new();
+
+    for article in articles(html) {
+        let mut in_tag = false;
+        let mut stripped = String::new();
+        for c in article.chars() {
+            match c {
+                '<' => in_tag = true,
+                '>' => in_tag = false,
+                c if !in_tag => stripped.push(c),
+                _ => {}
+            }
+        }
+        text.push_str(&decode_entities(&stripped));
+        text.push('\n');
+    }
+
+    text
+}
+
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = tag[start..].find('"')?;
+    Some(&tag[start..start + end])
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{html_to_markdown, html_to_text};
+
+    #[test]
+    fn converts_puzzles_to_markdown() {
+        let html = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Test ---</h2><p>The <em>elves</em> <em>need</em> <code>42</code> &lt;stars&gt;, see <a href="/2025/about">here</a>.</p>
+<pre><code>1 2
+3 4
+</code></pre>
+<ul><li>first</li><li>second</li></ul>
+</article>
+<p>Your puzzle answer was <code>7</code>.</p>
+</main></body></html>"#;
+
+        assert_eq!(
+            html_to_markdown(html),
+            "## --- Day 1: Test ---\n\nThe *elves* *need* `42` <stars>, see [here](/2025/about).\n\n```\n1 2\n3 4\n```\n\n- first\n- second\n"
+        );
+    }
+
+    #[test]
+    fn converts_responses_to_text() {
+        let html = r#"<main><article><p>That's not the right answer; your answer is too high. <a href="/2025/day/1">[Return to Day 1]</a></p></article></main>"#;
+        assert_eq!(
+            html_to_text(html),
+            "That's not the right answer; your answer is too high. [Return to Day 1]\n"
+        );
+    }
+}
@@ -0,0 +1,219 @@
+/// Module that abstracts over the ways of talking to Advent of Code.
+/// The backend is selected with the `AOC_BACKEND` environment variable, which can be set in `.cargo/config.toml`:
+///  - `aoc-cli`: shell out to "aoc-cli".
+///  - `native`: use the built-in client, requires the `native-client` feature.
+///  - `offline`: serve inputs, puzzles and answers from a fixture directory.
+///
+/// Without `AOC_BACKEND`, the built-in client is used if it is enabled and a session is configured, and "aoc-cli" otherwise.
+use std::{env, error::Error, fmt::Display, fs, path::PathBuf};
+
+use crate::template::Day;
+use crate::template::aoc_cli::AocCli;
+#[cfg(feature = "native-client")]
+use crate::template::aoc_client::{AocClient, AocClientError};
+use crate::template::submission::{Hint, Verdict};
+
+static DEFAULT_FIXTURES_DIR: &str = "./data/fixtures";
+
+/// Operations needed to work on a puzzle.
+pub trait PuzzleBackend {
+    /// Human-readable name of the backend, used in status messages.
+    fn name(&self) -> &'static str;
+
+    /// Fetch the puzzle input of a day.
+    fn input(&self, day: Day) -> Result<String, Box<dyn Error>>;
+
+    /// Fetch the puzzle description of a day as markdown.
+    fn description(&self, day: Day) -> Result<String, Box<dyn Error>>;
+
+    /// Submit an answer and classify the response.
+    fn submit(&self, day: Day, part: u8, answer: &str) -> Result<Verdict, Box<dyn Error>>;
+}
+
+#[derive(Debug)]
+pub enum BackendError {
+    UnknownBackend(String),
+    AocCliNotFound,
+    NativeClientDisabled,
+    #[cfg(feature = "native-client")]
+    NativeClient(AocClientError),
+}
+
+impl Display for BackendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackendError::UnknownBackend(name) => write!(
+                f,
+                "unknown backend \"{name}\". Set AOC_BACKEND to one of \"aoc-cli\", \"native\" or \"offline\"."
+            ),
+            BackendError::AocCliNotFound => write!(
+                f,
+                "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+            ),
+            BackendError::NativeClientDisabled => write!(
+                f,
+                "the native backend requires the \"native-client\" feature."
+            ),
+            #[cfg(feature = "native-client")]
+            BackendError::NativeClient(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for BackendError {}
+
+/// Select a backend as described in the module documentation.
+pub fn from_env() -> Result<Box<dyn PuzzleBackend>, BackendError> {
+    match env::var("AOC_BACKEND").ok().as_deref() {
+        None | Some("") => {
+            #[cfg(feature = "native-client")]
+            if let Ok(client) = AocClient::from_env() {
+                return Ok(Box::new(client));
+            }
+            aoc_cli()
+        }
+        Some("aoc-cli") => aoc_cli(),
+        Some("native") => native(),
+        Some("offline") => Ok(Box::new(Fixtures::from_env())),
+        Some(name) => Err(BackendError::UnknownBackend(name.into())),
+    }
+}
+
+fn aoc_cli() -> Result<Box<dyn PuzzleBackend>, BackendError> {
+    AocCli::new()
+        .map(|x| Box::new(x) as Box<dyn PuzzleBackend>)
+        .map_err(|_| BackendError::AocCliNotFound)
+}
+
+#[cfg(feature = "native-client")]
+fn native() -> Result<Box<dyn PuzzleBackend>, BackendError> {
+    AocClient::from_env()
+        .map(|x| Box::new(x) as Box<dyn PuzzleBackend>)
+        .map_err(BackendError::NativeClient)
+}
+
+#[cfg(not(feature = "native-client"))]
+fn native() -> Result<Box<dyn PuzzleBackend>, BackendError> {
+    Err(BackendError::NativeClientDisabled)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Offline backend that reads from a fixture directory, `AOC_FIXTURES` or `./data/fixtures` by default:
+///  - `inputs/{day}.txt` for puzzle inputs.
+///  - `puzzles/{day}.md` for puzzle descriptions.
+///  - `answers/{day}-{part}.txt` for the answers submissions are judged against.
+pub struct Fixtures {
+    dir: PathBuf,
+}
+
+impl Fixtures {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(env::var("AOC_FIXTURES").unwrap_or_else(|_| DEFAULT_FIXTURES_DIR.into()))
+    }
+
+    fn read(&self, folder: &str, file: String) -> Result<String, Box<dyn Error>> {
+        let path = self.dir.join(folder).join(file);
+        fs::read_to_string(&path)
+            .map_err(|e| format!("could not read fixture \"{}\": {e}", path.display()).into())
+    }
+}
+
+impl PuzzleBackend for Fixtures {
+    fn name(&self) -> &'static str {
+        "fixtures"
+    }
+
+    fn input(&self, day: Day) -> Result<String, Box<dyn Error>> {
+        self.read("inputs", format!("{day}.txt"))
+    }
+
+    fn description(&self, day: Day) -> Result<String, Box<dyn Error>> {
+        self.read("puzzles", format!("{day}.md"))
+    }
+
+    fn submit(&self, day: Day, part: u8, answer: &str) -> Result<Verdict, Box<dyn Error>> {
+        let expected = self.read("answers", format!("{day}-{part}.txt"))?;
+        Ok(judge(expected.trim_end(), answer.trim_end()))
+    }
+}
+
+/// Judge an answer like Advent of Code does, with hints for numeric answers.
+fn judge(expected: &str, answer: &str) -> Verdict {
+    if expected == answer {
+        return Verdict::Correct;
+    }
+
+    let hint = match (expected.parse::<i128>(), answer.parse::<i128>()) {
+        (Ok(expected), Ok(answer)) if answer > expected => Some(Hint::TooHigh),
+        (Ok(expected), Ok(answer)) if answer < expected => Some(Hint::TooLow),
+        _ => None,
+    };
+
+    Verdict::Incorrect { hint, wait: None }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs};
+
+    use super::{Fixtures, PuzzleBackend, judge};
+    use crate::day;
+    use crate::template::submission::{Hint, Verdict};
+
+    #[test]
+    fn judges_answers() {
+        assert_eq!(judge("42", "42"), Verdict::Correct);
+        assert_eq!(
+            judge("42", "50"),
+            Verdict::Incorrect {
+                hint: Some(Hint::TooHigh),
+                wait: None
+            }
+        );
+        assert_eq!(
+            judge("42", "-1"),
+            Verdict::Incorrect {
+                hint: Some(Hint::TooLow),
+                wait: None
+            }
+        );
+        assert_eq!(
+            judge("ABC", "ABD"),
+            Verdict::Incorrect {
+                hint: None,
+                wait: None
+            }
+        );
+    }
+
+    #[test]
+    fn serves_fixtures() {
+        let dir = env::temp_dir().join(format!("aoc-fixtures-{}", std::process::id()));
+        fs::create_dir_all(dir.join("inputs")).unwrap();
+        fs::create_dir_all(dir.join("answers")).unwrap();
+        fs::write(dir.join("inputs/01.txt"), "1 2 3\n").unwrap();
+        fs::write(dir.join("answers/01-1.txt"), "6\n").unwrap();
+
+        let fixtures = Fixtures::new(&dir);
+        assert_eq!(fixtures.input(day!(1)).unwrap(), "1 2 3\n");
+        assert!(fixtures.description(day!(1)).is_err());
+        assert_eq!(fixtures.submit(day!(1), 1, "6").unwrap(), Verdict::Correct);
+        assert_eq!(
+            fixtures.submit(day!(1), 1, "5").unwrap(),
+            Verdict::Incorrect {
+                hint: Some(Hint::TooLow),
+                wait: None
+            }
+        );
+        assert!(fixtures.submit(day!(1), 2, "6").is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
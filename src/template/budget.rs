@@ -0,0 +1,254 @@
+/// Module for the budget of benchmarks: how long and how often a part is run when timed.
+///
+/// The budget defaults to approx. 1 second of execution time, clamped to 10 to 10.000 iterations.
+/// It can be configured with `AOC_BENCH_*` environment variables and overridden per run, see [`BenchBudget::from_args`].
+use std::{cmp, collections::HashMap, env, error::Error, time::Duration};
+use tinyjson::JsonValue;
+
+use crate::template::runner::parse_timeout;
+
+/// How long and how often a part is benched.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchBudget {
+    /// Time to spend on measured iterations, their number is derived from the first execution.
+    pub target: Duration,
+    pub min_iterations: u32,
+    pub max_iterations: u32,
+    /// Iterations run up front and discarded, a tenth of the measured iterations if not set.
+    pub warm_up: Option<u32>,
+}
+
+impl Default for BenchBudget {
+    fn default() -> Self {
+        Self {
+            target: Duration::from_secs(1),
+            min_iterations: 10,
+            max_iterations: 10_000,
+            warm_up: None,
+        }
+    }
+}
+
+impl BenchBudget {
+    /// A small budget for quick feedback, e.g. in CI: `--quick`.
+    pub const QUICK: Self = Self {
+        target: Duration::from_millis(100),
+        min_iterations: 3,
+        max_iterations: 100,
+        warm_up: Some(1),
+    };
+
+    /// Number of measured iterations of a part whose first execution took `base_time`.
+    pub fn iterations(&self, base_time: Duration) -> u32 {
+        let iterations = self.target.as_nanos() / cmp::max(base_time.as_nanos(), 10);
+        let iterations = u32::try_from(iterations).unwrap_or(u32::MAX);
+        iterations.clamp(self.min_iterations, self.max_iterations)
+    }
+
+    /// Number of warm-up iterations before `iterations` measured ones.
+    pub fn warm_up_iterations(&self, iterations: u32) -> u32 {
+        self.warm_up.unwrap_or(iterations / 10)
+    }
+
+    /// The budget configured with `AOC_BENCH_TIME`, `AOC_BENCH_MIN_ITERATIONS`,
+    /// `AOC_BENCH_MAX_ITERATIONS` and `AOC_BENCH_WARM_UP`, using defaults for unset values.
+    pub fn from_env() -> Result<Self, Box<dyn Error>> {
+        let var = |key: &str| env::var(key).ok().filter(|x| !x.is_empty());
+        let defaults = Self::default();
+
+        let budget = Self {
+            target: match var("AOC_BENCH_TIME") {
+                Some(x) => parse_timeout(&x).map_err(|e| format!("AOC_BENCH_TIME: {e}"))?,
+                None => defaults.target,
+            },
+            min_iterations: parse_env_count("AOC_BENCH_MIN_ITERATIONS", var)?
+                .unwrap_or(defaults.min_iterations),
+            max_iterations: parse_env_count("AOC_BENCH_MAX_ITERATIONS", var)?
+                .unwrap_or(defaults.max_iterations),
+            warm_up: parse_env_count("AOC_BENCH_WARM_UP", var)?,
+        };
+
+        budget.validate()
+    }
+
+    /// Parse the budget options of a run on top of [`BenchBudget::from_env`]:
+    /// `--quick`, `--bench-time <duration>`, `--min-iterations <n>`, `--max-iterations <n>`,
+    /// `--iterations <n>` to run exactly `n` iterations, and `--warm-up <n>`.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, Box<dyn Error>> {
+        let mut budget = if args.contains("--quick") {
+            Self::QUICK
+        } else {
+            Self::from_env()?
+        };
+
+        if let Some(target) = args.opt_value_from_fn("--bench-time", parse_timeout)? {
+            budget.target = target;
+        }
+        if let Some(min_iterations) = args.opt_value_from_str("--min-iterations")? {
+            budget.min_iterations = min_iterations;
+        }
+        if let Some(max_iterations) = args.opt_value_from_str("--max-iterations")? {
+            budget.max_iterations = max_iterations;
+        }
+        if let Some(iterations) = args.opt_value_from_str("--iterations")? {
+            budget.min_iterations = iterations;
+            budget.max_iterations = iterations;
+        }
+        if let Some(warm_up) = args.opt_value_from_str("--warm-up")? {
+            budget.warm_up = Some(warm_up);
+        }
+
+        budget.validate()
+    }
+
+    /// The arguments that pass this budget to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "--bench-time".to_string(),
+            format!("{}s", self.target.as_secs_f64()),
+            "--min-iterations".to_string(),
+            self.min_iterations.to_string(),
+            "--max-iterations".to_string(),
+            self.max_iterations.to_string(),
+        ];
+        if let Some(warm_up) = self.warm_up {
+            args.extend(["--warm-up".to_string(), warm_up.to_string()]);
+        }
+        args
+    }
+
+    fn validate(self) -> Result<Self, Box<dyn Error>> {
+        if self.min_iterations == 0 {
+            return Err("expecting at least one benchmark iteration".into());
+        }
+        if self.min_iterations > self.max_iterations {
+            return Err("the minimum number of iterations exceeds the maximum".into());
+        }
+        Ok(self)
+    }
+}
+
+fn parse_env_count(
+    key: &str,
+    var: impl Fn(&str) -> Option<String>,
+) -> Result<Option<u32>, Box<dyn Error>> {
+    var(key)
+        .map(|x| x.parse().map_err(|e| format!("{key}: {e}").into()))
+        .transpose()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&BenchBudget> for JsonValue {
+    fn from(value: &BenchBudget) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "target_nanos".into(),
+            JsonValue::Number(value.target.as_nanos() as f64),
+        );
+        map.insert(
+            "min_iterations".into(),
+            JsonValue::Number(f64::from(value.min_iterations)),
+        );
+        map.insert(
+            "max_iterations".into(),
+            JsonValue::Number(f64::from(value.max_iterations)),
+        );
+        map.insert(
+            "warm_up".into(),
+            value
+                .warm_up
+                .map_or(JsonValue::Null, |x| JsonValue::Number(f64::from(x))),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchBudget {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected budget to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or_else(|| format!("Expected budget.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(BenchBudget {
+            target: Duration::from_nanos(number("target_nanos")? as u64),
+            min_iterations: number("min_iterations")? as u32,
+            max_iterations: number("max_iterations")? as u32,
+            warm_up: json
+                .get("warm_up")
+                .and_then(|v| v.get::<f64>())
+                .map(|x| *x as u32),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::BenchBudget;
+
+    fn parse(args: &str) -> Result<BenchBudget, Box<dyn std::error::Error>> {
+        let args = args.split_whitespace().map(Into::into).collect();
+        BenchBudget::from_args(&mut pico_args::Arguments::from_vec(args))
+    }
+
+    #[test]
+    fn derives_iterations_from_the_first_execution() {
+        let budget = BenchBudget::default();
+        assert_eq!(budget.iterations(Duration::from_millis(1)), 1000);
+        assert_eq!(budget.iterations(Duration::from_secs(2)), 10);
+        assert_eq!(budget.iterations(Duration::from_nanos(1)), 10_000);
+        assert_eq!(budget.warm_up_iterations(1000), 100);
+    }
+
+    #[test]
+    fn parses_budget_arguments() {
+        assert_eq!(parse("--quick").unwrap(), BenchBudget::QUICK);
+
+        let budget = parse("--bench-time 5s --max-iterations 20 --warm-up 2").unwrap();
+        assert_eq!(budget.target, Duration::from_secs(5));
+        assert_eq!(budget.max_iterations, 20);
+        assert_eq!(budget.warm_up_iterations(20), 2);
+
+        let budget = parse("--iterations 50").unwrap();
+        assert_eq!(budget.iterations(Duration::from_nanos(1)), 50);
+        assert_eq!(budget.iterations(Duration::from_secs(60)), 50);
+    }
+
+    #[test]
+    fn rejects_invalid_budgets() {
+        assert!(parse("--min-iterations 20 --max-iterations 10").is_err());
+        assert!(parse("--iterations 0").is_err());
+    }
+
+    #[test]
+    fn roundtrips_budgets() {
+        let budget = parse(&BenchBudget::QUICK.to_args().join(" ")).unwrap();
+        assert_eq!(budget, BenchBudget::QUICK);
+
+        let sub_millis = BenchBudget {
+            target: Duration::from_micros(500),
+            ..BenchBudget::QUICK
+        };
+        assert_eq!(parse(&sub_millis.to_args().join(" ")).unwrap(), sub_millis);
+
+        let json = JsonValue::from(&budget);
+        assert_eq!(BenchBudget::try_from(&json).unwrap(), budget);
+    }
+}
@@ -1,5 +1,31 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::time::Duration;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{
+    Year, all_days_in,
+    budget::BenchBudget,
+    record::OutputFormat,
+    registry::Solution,
+    run_multi::{RunOptions, run_multi},
+};
+
+/// Run all days of a year, or of `AOC_YEAR` without one.
+pub fn handle(
+    year: Option<Year>,
+    is_release: bool,
+    format: OutputFormat,
+    jobs: usize,
+    timeout: Option<Duration>,
+    solutions: &'static [Solution],
+) {
+    let options = RunOptions {
+        is_release,
+        is_timed: false,
+        format,
+        jobs,
+        timeout,
+        verify: false,
+        part: None,
+        budget: BenchBudget::default(),
+    };
+    run_multi(&all_days_in(year).collect(), options, solutions);
 }
@@ -1,16 +1,99 @@
-use crate::template::{Day, aoc_cli};
-use std::process;
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+    process,
+};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
+use crate::template::backend::{self, PuzzleBackend};
+use crate::template::commands::scaffold;
+use crate::template::{Day, puzzle};
+
+/// Download a day and fill in its example from the puzzle description, using its `example_block`-th code block.
+pub fn handle(day: Day, example_block: usize) {
+    let backend = backend::from_env().unwrap_or_else(|e| {
+        eprintln!("{e}");
         process::exit(1);
-    }
+    });
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = download(backend.as_ref(), day) {
+        eprintln!("failed to download puzzle via {}: {e}", backend.name());
         process::exit(1);
     };
+
+    scaffold::fill_example(day, example_block);
+}
+
+/// Download the input and puzzle description of a day to the data directory.
+pub fn download(backend: &dyn PuzzleBackend, day: Day) -> Result<(), Box<dyn Error>> {
+    let input_path = PathBuf::from(format!("data/inputs/{day}.txt"));
+    let puzzle_path = PathBuf::from(puzzle::get_path(day));
+
+    download_to(backend, day, &input_path, &puzzle_path)?;
+
+    println!("---");
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+    Ok(())
+}
+
+fn download_to(
+    backend: &dyn PuzzleBackend,
+    day: Day,
+    input_path: &Path,
+    puzzle_path: &Path,
+) -> Result<(), Box<dyn Error>> {
+    fs::write(input_path, backend.input(day)?)?;
+    fs::write(puzzle_path, backend.description(day)?)?;
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs};
+
+    use super::download_to;
+    use crate::day;
+    use crate::template::backend::{Fixtures, PuzzleBackend};
+    use crate::template::submission::Verdict;
+
+    #[test]
+    fn downloads_and_submits_offline() {
+        let dir = env::temp_dir().join(format!("aoc-download-{}", std::process::id()));
+        for folder in ["inputs", "puzzles", "answers"] {
+            fs::create_dir_all(dir.join(folder)).unwrap();
+        }
+        fs::write(dir.join("inputs/2024_05.txt"), "1\n2\n3\n").unwrap();
+        fs::write(dir.join("puzzles/2024_05.md"), "## --- Day 5 ---\n").unwrap();
+        fs::write(dir.join("answers/2024_05-1.txt"), "6\n").unwrap();
+
+        let fixtures = Fixtures::new(&dir);
+        let (input_path, puzzle_path) = (dir.join("input.txt"), dir.join("puzzle.md"));
+        download_to(&fixtures, day!(2024, 5), &input_path, &puzzle_path).unwrap();
+
+        let input = fs::read_to_string(&input_path).unwrap();
+        assert_eq!(
+            fs::read_to_string(&puzzle_path).unwrap(),
+            "## --- Day 5 ---\n"
+        );
+
+        let answer: u32 = input.lines().map(|x| x.parse::<u32>().unwrap()).sum();
+        assert_eq!(
+            fixtures
+                .submit(day!(2024, 5), 1, &answer.to_string())
+                .unwrap(),
+            Verdict::Correct
+        );
+        assert!(download_to(&fixtures, day!(2024, 6), &input_path, &puzzle_path).is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
 }
@@ -1,17 +1,24 @@
-use std::process;
+use std::{error::Error, fs, process};
 
-use crate::template::{Day, aoc_cli};
+use crate::template::backend::{self, PuzzleBackend};
+use crate::template::{Day, puzzle};
 
 pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
+    let backend = backend::from_env().unwrap_or_else(|e| {
+        eprintln!("{e}");
         process::exit(1);
-    }
+    });
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = read(backend.as_ref(), day) {
+        eprintln!("failed to read puzzle via {}: {e}", backend.name());
         process::exit(1);
     };
 }
+
+/// Print the puzzle description of a day, and store it in the puzzle file.
+pub fn read(backend: &dyn PuzzleBackend, day: Day) -> Result<(), Box<dyn Error>> {
+    let description = backend.description(day)?;
+    fs::write(puzzle::get_path(day), &description)?;
+    println!("{description}");
+    Ok(())
+}
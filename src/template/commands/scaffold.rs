@@ -1,10 +1,10 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
     process,
 };
 
-use crate::template::Day;
+use crate::template::{Day, puzzle};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
@@ -40,13 +40,19 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
+    // days of a specific year name it in the `solution!` macro.
+    let solution_args = match day.year() {
+        Some(year) => format!("year = {year}, {}", day.into_inner()),
+        None => day.into_inner().to_string(),
+    };
+
     match file.write_all(
         MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+            .replace("%DAY_NUMBER%", &solution_args)
             .as_bytes(),
     ) {
         Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created module file \"{}\"", module_path);
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {e}");
@@ -56,7 +62,7 @@ pub fn handle(day: Day, overwrite: bool) {
 
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty input file \"{}\"", input_path);
         }
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
@@ -66,7 +72,7 @@ pub fn handle(day: Day, overwrite: bool) {
 
     match create_file(&example_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty example file \"{}\"", example_path);
         }
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
@@ -77,3 +83,106 @@ pub fn handle(day: Day, overwrite: bool) {
     println!("---");
     println!("🎄 Type `cargo solve {day}` to run your solution.");
 }
+
+/// Fill in the example of a day from its downloaded puzzle description:
+///  1. the `block`-th code block of part one is written to the example file, if that is empty.
+///  2. the example answers are filled into the tests of the module, if these still expect `None`.
+pub fn fill_example(day: Day, block: usize) {
+    let Some(description) = puzzle::read(day) else {
+        return;
+    };
+
+    let example_path = format!("data/examples/{day}.txt");
+    let module_path = format!("src/bin/{day}.rs");
+
+    let example = puzzle::part(&description, 1)
+        .map(puzzle::code_blocks)
+        .and_then(|blocks| blocks.into_iter().nth(block.saturating_sub(1)));
+    let is_example_empty = fs::read_to_string(&example_path).map_or(true, |x| x.is_empty());
+
+    if let Some(example) = example
+        && is_example_empty
+    {
+        match fs::write(&example_path, example) {
+            Ok(()) => println!("🎄 Wrote code block {block} as example to \"{example_path}\"."),
+            Err(e) => eprintln!("Failed to write example file: {e}"),
+        }
+    }
+
+    let Ok(mut source) = fs::read_to_string(&module_path) else {
+        return;
+    };
+    let mut is_filled = false;
+
+    for part in [1, 2] {
+        let answer = puzzle::part(&description, part).and_then(puzzle::example_answer);
+        if let Some(answer) = answer
+            && let Some(filled) = fill_assertion(&source, part, &answer)
+        {
+            println!("🎄 Filled in example answer {answer} for part {part}.");
+            source = filled;
+            is_filled = true;
+        }
+    }
+
+    if is_filled && let Err(e) = fs::write(&module_path, source) {
+        eprintln!("Failed to write module file: {e}");
+    }
+}
+
+/// Replace the `None` assertion of a part's test, as created from the template, with an answer.
+/// Returns `None` if the test has been changed already, or if the answer is not a `u64` like
+/// the template's parts return, e.g. a negative number.
+fn fill_assertion(source: &str, part: u8, answer: &str) -> Option<String> {
+    let answer: u64 = answer.parse().ok()?;
+    let test_name = match part {
+        1 => "fn test_part_one()",
+        2 => "fn test_part_two()",
+        _ => return None,
+    };
+    let placeholder = "assert_eq!(result, None);";
+
+    let test_start = source.find(test_name)?;
+    let test_end = source[test_start + test_name.len()..]
+        .find("fn ")
+        .map_or(source.len(), |x| test_start + test_name.len() + x);
+    let assertion = test_start + source[test_start..test_end].find(placeholder)?;
+
+    let mut filled = source.to_string();
+    filled.replace_range(
+        assertion..assertion + placeholder.len(),
+        &format!("assert_eq!(result, Some({answer}));"),
+    );
+    Some(filled)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{MODULE_TEMPLATE, fill_assertion};
+
+    #[test]
+    fn fills_assertions() {
+        let source = MODULE_TEMPLATE.replace("%DAY_NUMBER%", "1");
+
+        let filled = fill_assertion(&source, 2, "29").unwrap();
+        assert!(filled.contains("assert_eq!(result, Some(29));"));
+        assert_eq!(filled.matches("assert_eq!(result, None);").count(), 1);
+
+        let filled = fill_assertion(&filled, 1, "142").unwrap();
+        let part_one = filled.find("Some(142)").unwrap();
+        let part_two = filled.find("Some(29)").unwrap();
+        assert!(part_one < part_two);
+
+        assert_eq!(fill_assertion(&filled, 1, "142"), None);
+    }
+
+    #[test]
+    fn skips_answers_that_are_not_u64() {
+        let source = MODULE_TEMPLATE.replace("%DAY_NUMBER%", "1");
+
+        assert_eq!(fill_assertion(&source, 1, "-7"), None);
+        assert_eq!(fill_assertion(&source, 1, "18446744073709551616"), None);
+    }
+}
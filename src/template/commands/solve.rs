@@ -1,34 +1,197 @@
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
 
-use crate::template::Day;
+use crate::template::record::{OutputFormat, PartRecord};
+use crate::template::runner::InputSource;
+use crate::template::{ANSI_ITALIC, ANSI_RED, ANSI_RESET, Day};
+
+/// How often `--watch` checks the watched files for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// Options of `cargo solve`, most of them are forwarded to the solution binary.
+#[derive(Clone, Debug, Default)]
+pub struct SolveOptions {
+    pub release: bool,
+    pub dhat: bool,
+    pub submit_part: Option<u8>,
+    pub force: bool,
+    pub format: OutputFormat,
+    pub timeout: Option<Duration>,
+    pub input: InputSource,
+    /// Only run this part.
+    pub part: Option<u8>,
+    /// Run the tests of the day first, and only solve it if they pass.
+    pub run_tests: bool,
+}
+
+pub fn handle(day: Day, options: &SolveOptions) {
+    if options.run_tests && !run_tests(day) {
+        eprintln!("{ANSI_RED}Tests of day {day} failed.{ANSI_RESET}");
+        return;
+    }
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
     let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
 
-    if dhat {
+    if options.dhat {
         cmd_args.extend([
             "--profile".to_string(),
             "dhat".to_string(),
             "--features".to_string(),
             "dhat-heap".to_string(),
         ]);
-    } else if release {
+    } else if options.release {
         cmd_args.push("--release".to_string());
     }
 
+    if cfg!(feature = "native-client") {
+        // the solution binary submits answers, so it needs to be built with the client as well.
+        cmd_args.extend(["--features".to_string(), "native-client".to_string()]);
+    }
+
     cmd_args.push("--".to_string());
 
-    if let Some(submit_part) = submit_part {
+    if let Some(submit_part) = options.submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
+
+        if options.force {
+            cmd_args.push("--force".to_string());
+        }
+    }
+
+    if options.format != OutputFormat::Text {
+        cmd_args.push("--format".to_string());
+        cmd_args.push(options.format.to_string());
+    }
+
+    if let Some(timeout) = options.timeout {
+        cmd_args.push("--timeout".to_string());
+        cmd_args.push(format!("{}s", timeout.as_secs_f64()));
+    }
+
+    if let Some(part) = options.part {
+        cmd_args.push("--part".to_string());
+        cmd_args.push(part.to_string());
     }
 
+    match &options.input {
+        InputSource::Puzzle => {}
+        InputSource::Example(name) => {
+            cmd_args.push("--example".to_string());
+            cmd_args.extend(name.clone());
+        }
+        InputSource::File(path) => {
+            cmd_args.extend(["--input".to_string(), path.clone()]);
+        }
+    }
+
+    let is_json = options.format == OutputFormat::Json;
+
+    // stdin is inherited, so `--input -` reads from the input piped to `cargo solve`.
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
-        .stdout(Stdio::inherit())
+        .stdout(if is_json {
+            Stdio::piped()
+        } else {
+            Stdio::inherit()
+        })
         .stderr(Stdio::inherit())
         .spawn()
         .unwrap();
 
+    // keep stdout machine-readable by moving any other output of the solution to stderr.
+    if let Some(stdout) = cmd.stdout.take() {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if line.parse::<PartRecord>().is_ok() {
+                println!("{line}");
+            } else {
+                eprintln!("{line}");
+            }
+        }
+    }
+
     cmd.wait().unwrap();
 }
+
+/// Solve a day whenever its source, a library module, its input or one of its examples changes.
+/// Runs until interrupted.
+pub fn watch(day: Day, options: &SolveOptions) -> Result<(), String> {
+    if options.submit_part.is_some() {
+        return Err("`--watch` can not be combined with `--submit`.".into());
+    }
+    if options.input == InputSource::File("-".into()) {
+        return Err("`--watch` can not read the input from stdin.".into());
+    }
+
+    let mut last_snapshot = None;
+
+    loop {
+        let snapshot = snapshot(&watched_files(day));
+
+        // files that change while the day runs are picked up by the next poll.
+        if last_snapshot.as_ref() != Some(&snapshot) {
+            print!("{CLEAR_SCREEN}");
+            println!("{ANSI_ITALIC}Watching day {day}, press Ctrl+C to stop.{ANSI_RESET}\n");
+            handle(day, options);
+            last_snapshot = Some(snapshot);
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn run_tests(day: Day) -> bool {
+    Command::new("cargo")
+        .args(["test", "--bin", &day.to_string()])
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// The files that affect the result of a day.
+fn watched_files(day: Day) -> Vec<PathBuf> {
+    let mut files = vec![
+        PathBuf::from(format!("src/bin/{day}.rs")),
+        PathBuf::from(format!("data/inputs/{day}.txt")),
+    ];
+
+    // modules shared by all days, e.g. `src/lib.rs`.
+    files.extend(list_dir("src").filter(|path| path.extension().is_some_and(|x| x == "rs")));
+
+    // the example, numbered examples like `01-2.txt` and named examples with their answers.
+    files.extend(list_dir("data/examples").filter(|path| {
+        path.file_stem()
+            .and_then(|x| x.to_str())
+            .is_some_and(|x| x == day.to_string() || x.starts_with(&format!("{day}-")))
+    }));
+    files.extend(list_dir(format!("data/examples/{day}")));
+
+    files
+}
+
+fn list_dir(dir: impl AsRef<Path>) -> impl Iterator<Item = PathBuf> {
+    fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+}
+
+/// The modification time of every file, `None` for files that do not exist.
+fn snapshot(files: &[PathBuf]) -> Vec<(PathBuf, Option<SystemTime>)> {
+    let mut snapshot: Vec<_> = files
+        .iter()
+        .map(|path| {
+            let modified = fs::metadata(path).and_then(|x| x.modified()).ok();
+            (path.clone(), modified)
+        })
+        .collect();
+    snapshot.sort();
+    snapshot
+}
@@ -1,19 +1,69 @@
 use std::collections::HashSet;
+use std::process;
+use std::time::Duration;
 
-use crate::template::run_multi::run_multi;
+use crate::template::budget::BenchBudget;
+use crate::template::record::{OutputFormat, OutputLine};
+use crate::template::registry::Solution;
+use crate::template::run_multi::{RunOptions, run_multi};
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{
+    ANSI_BOLD, ANSI_GREEN, ANSI_ITALIC, ANSI_RED, ANSI_RESET, Day, Year, all_days_in, compare,
+    history, readme_benchmarks,
+};
+
+/// Options of `cargo time`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TimeOptions {
+    /// Also bench days that are fully benched already.
+    pub run_all: bool,
+    pub store: bool,
+    pub format: OutputFormat,
+    /// Compare against the stored timings, failing if any part regressed beyond this percentage.
+    pub compare_threshold: Option<f64>,
+    pub timeout: Option<Duration>,
+    /// Only bench and store this part of every day.
+    pub part: Option<u8>,
+    /// How long and how often parts are benched.
+    pub budget: BenchBudget,
+}
+
+/// Benchmark solutions. If a compare threshold is set, results are compared against the stored
+/// timings and the process exits with a non-zero status if any part regressed beyond it.
+/// Without a `day`, the days of `year` are benched, or the ones of `AOC_YEAR` without a year.
+pub fn handle(
+    day: Option<Day>,
+    year: Option<Year>,
+    options: TimeOptions,
+    solutions: &'static [Solution],
+) {
+    let TimeOptions {
+        run_all,
+        store,
+        format,
+        compare_threshold,
+        timeout,
+        part,
+        budget,
+    } = options;
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
     let stored_timings = Timings::read_from_file();
 
     let days_to_run = day.map_or_else(
         || {
             if run_all {
-                all_days().collect()
+                all_days_in(year).collect()
+            } else if compare_threshold.is_some() {
+                // when comparing, bench every day that has a baseline.
+                stored_timings
+                    .data
+                    .iter()
+                    .map(|t| t.day)
+                    .filter(|day| day.year() == year)
+                    .collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
+                all_days_in(year)
                     .filter(|day| !stored_timings.is_day_complete(*day))
                     .collect()
             }
@@ -21,20 +71,121 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let options = RunOptions {
+        is_release: true,
+        is_timed: true,
+        format,
+        jobs: 1,
+        timeout,
+        verify: false,
+        part,
+        budget,
+    };
+    let timings = run_multi(&days_to_run, options, solutions).timings;
+
+    let has_regressions = compare_threshold.is_some_and(|threshold| {
+        let changes = compare::compare(&stored_timings, &timings);
+
+        for line in compare::format_report(&changes, threshold) {
+            OutputLine::message(format, line).print();
+        }
+
+        changes.iter().any(|c| c.is_regression(threshold))
+    });
+
+    if store && has_regressions {
+        eprintln!("Not storing benchmarks because of regressions.");
+    } else if store {
+        let merged_timings = match part {
+            Some(part) => stored_timings.merge_part(&timings, part),
+            None => stored_timings.merge(&timings),
+        };
+
+        // record the merged timings of the benched days, so that parts that were not benched
+        // do not show up as improvements in the history.
+        let benched_timings = Timings {
+            data: merged_timings
+                .data
+                .iter()
+                .filter(|t| timings.data.iter().any(|x| x.day == t.day))
+                .cloned()
+                .collect(),
+        };
+        if let Err(e) = history::append(&benched_timings) {
+            eprintln!("Failed to append to timing history: {e}");
+        }
 
-    if store {
-        let merged_timings = stored_timings.merge(&timings);
         merged_timings.store_file().unwrap();
 
-        println!();
         match readme_benchmarks::update(merged_timings) {
             Ok(()) => {
-                println!("Stored updated benchmarks.");
+                if format == OutputFormat::Text {
+                    println!();
+                    println!("Stored updated benchmarks.");
+                }
             }
             Err(_) => {
                 eprintln!("Failed to store updated benchmarks.");
             }
         }
     }
+
+    if has_regressions {
+        process::exit(1);
+    }
+}
+
+/// List the stored timing history of a day, oldest first.
+pub fn handle_history(day: Day) {
+    let entries = history::read_for_day(day);
+
+    println!("{ANSI_BOLD}Day {day} history{ANSI_RESET}");
+    println!("--------------");
+
+    if entries.is_empty() {
+        println!("No timings stored yet. Run `cargo time {day} --store` to record some.");
+        return;
+    }
+
+    let mut previous_total: Option<f64> = None;
+
+    for entry in &entries {
+        let total = entry.total_nanos();
+
+        let change = match previous_total {
+            Some(previous) if previous > 0.0 => {
+                let percent = (total - previous) / previous * 100.0;
+                if percent > 0.0 {
+                    format!(" {ANSI_RED}▲ {percent:+.1}%{ANSI_RESET}")
+                } else if percent < 0.0 {
+                    format!(" {ANSI_GREEN}▼ {percent:+.1}%{ANSI_RESET}")
+                } else {
+                    String::new()
+                }
+            }
+            _ => String::new(),
+        };
+        previous_total = Some(total);
+
+        let parse = entry
+            .parse_nanos
+            .map(|x| format!(" | Parse: {}", format_nanos(Some(x))))
+            .unwrap_or_default();
+
+        println!(
+            "{} {ANSI_ITALIC}{} @ {}{ANSI_RESET}{parse} | Part 1: {} | Part 2: {}{change}",
+            history::format_timestamp(entry.timestamp),
+            entry.commit.as_deref().unwrap_or("-"),
+            entry.machine.as_deref().unwrap_or("-"),
+            format_nanos(entry.part_1_nanos),
+            format_nanos(entry.part_2_nanos),
+        );
+    }
+}
+
+fn format_nanos(nanos: Option<f64>) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    nanos.map_or("-".into(), |x| {
+        format!("{:.1?}", Duration::from_nanos(x as u64))
+    })
 }
@@ -0,0 +1,43 @@
+use std::process;
+use std::time::Duration;
+
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, Year, all_days_in,
+    budget::BenchBudget,
+    record::OutputFormat,
+    registry::Solution,
+    run_multi::{RunOptions, run_multi},
+};
+
+/// Run all days of a year on their real input and check the answers against the ones recorded in
+/// `data/answers`. Exits with a non-zero status if any answer does not match.
+pub fn handle(
+    year: Option<Year>,
+    is_release: bool,
+    jobs: usize,
+    timeout: Option<Duration>,
+    solutions: &'static [Solution],
+) {
+    let options = RunOptions {
+        is_release,
+        is_timed: false,
+        format: OutputFormat::Text,
+        jobs,
+        timeout,
+        verify: true,
+        part: None,
+        budget: BenchBudget::default(),
+    };
+
+    let summary = run_multi(&all_days_in(year).collect(), options, solutions);
+
+    println!();
+    println!(
+        "{ANSI_BOLD}Verified:{ANSI_RESET} {} correct, {} incorrect.",
+        summary.correct, summary.incorrect
+    );
+
+    if summary.incorrect > 0 {
+        process::exit(1);
+    }
+}
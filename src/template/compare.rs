@@ -0,0 +1,296 @@
+/// Module that compares fresh benchmark results against the stored baseline.
+use std::time::Duration;
+
+use crate::template::record::{PARSE_PART, part_label};
+use crate::template::timings::Timings;
+use crate::template::{ANSI_BOLD, ANSI_GREEN, ANSI_ITALIC, ANSI_RED, ANSI_RESET, Day};
+
+/// Regression threshold in percent used when `--threshold` is not passed.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Change of the duration of a single part, or of the parser of a day, between two benchmark runs.
+/// Medians are compared if both runs have statistics, averages otherwise.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartChange {
+    pub day: Day,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// Both runs recorded their bench budget and it differs, so the durations may not be comparable.
+    pub budget_differs: bool,
+}
+
+impl PartChange {
+    /// Relative change in percent, positive values mean the part got slower.
+    pub fn percent(&self) -> f64 {
+        let baseline = self.baseline.as_nanos() as f64;
+        let current = self.current.as_nanos() as f64;
+        if baseline == 0.0 {
+            return 0.0;
+        }
+        (current - baseline) / baseline * 100.0
+    }
+
+    /// Whether the part got slower beyond `threshold`. Parts benched with a different budget than
+    /// their baseline are not counted, as fewer samples alone can change their duration.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        !self.budget_differs && self.percent() > threshold
+    }
+}
+
+/// Pair up all parts that are present in both the baseline and the current run.
+/// Parts that timed out in either run are skipped.
+pub fn compare(baseline: &Timings, current: &Timings) -> Vec<PartChange> {
+    let mut changes = vec![];
+
+    for timing in &current.data {
+        let Some(stored) = baseline.data.iter().find(|t| t.day == timing.day) else {
+            continue;
+        };
+
+        let parts = [
+            (PARSE_PART, stored.parse, timing.parse),
+            (1, stored.part_1, timing.part_1),
+            (2, stored.part_2, timing.part_2),
+        ];
+
+        for (part, stored_part, current_part) in parts {
+            let (Some(stored_part), Some(current_part)) = (stored_part, current_part) else {
+                continue;
+            };
+
+            if !stored_part.is_measured() || !current_part.is_measured() {
+                continue;
+            }
+
+            let (baseline, current) = match (stored_part.stats, current_part.stats) {
+                (Some(stored_stats), Some(stats)) => (stored_stats.median, stats.median),
+                _ => (stored_part.duration(), current_part.duration()),
+            };
+
+            let budget_differs = matches!(
+                (stored_part.budget, current_part.budget),
+                (Some(stored_budget), Some(budget)) if stored_budget != budget
+            );
+
+            changes.push(PartChange {
+                day: timing.day,
+                part,
+                baseline,
+                current,
+                budget_differs,
+            });
+        }
+    }
+
+    changes
+}
+
+/// Format a comparison report, one line per part.
+pub fn format_report(changes: &[PartChange], threshold: f64) -> Vec<String> {
+    let mut lines = vec![format!(
+        "{ANSI_BOLD}Comparison against stored timings{ANSI_RESET} {ANSI_ITALIC}(threshold {threshold:.1}%){ANSI_RESET}"
+    )];
+
+    if changes.is_empty() {
+        lines.push("No stored timings to compare against.".into());
+    }
+
+    for change in changes {
+        let percent = change.percent();
+        let marker = if change.is_regression(threshold) {
+            format!("{ANSI_RED}▲ {percent:+.1}% regression{ANSI_RESET}")
+        } else if percent > 0.0 {
+            format!("{ANSI_RED}▲{ANSI_RESET} {percent:+.1}%")
+        } else if percent < 0.0 {
+            format!("{ANSI_GREEN}▼{ANSI_RESET} {percent:+.1}%")
+        } else {
+            "= 0.0%".into()
+        };
+
+        let note = if change.budget_differs {
+            format!(" {ANSI_ITALIC}(different bench budget){ANSI_RESET}")
+        } else {
+            String::new()
+        };
+
+        lines.push(format!(
+            "Day {} {}: {:.1?} → {:.1?} {marker}{note}",
+            change.day,
+            part_label(change.part),
+            change.baseline,
+            change.current
+        ));
+    }
+
+    lines
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{PartChange, compare, format_report};
+    use crate::{
+        day,
+        template::{
+            Day,
+            budget::BenchBudget,
+            stats::BenchStats,
+            timings::{PartTiming, Timing, Timings},
+        },
+    };
+
+    fn get_mock_timing(day: Day, part_1: Option<u64>, part_2: Option<u64>) -> Timing {
+        let part = |nanos: u64| {
+            PartTiming::from_stats(BenchStats::from_samples(&[Duration::from_nanos(nanos)]))
+        };
+        Timing {
+            day,
+            parse: None,
+            part_1: part_1.map(part),
+            part_2: part_2.map(part),
+            total_nanos: (part_1.unwrap_or(0) + part_2.unwrap_or(0)) as f64,
+        }
+    }
+
+    #[test]
+    fn compares_overlapping_parts() {
+        let baseline = Timings {
+            data: vec![
+                get_mock_timing(day!(1), Some(100), Some(200)),
+                get_mock_timing(day!(2), Some(100), None),
+            ],
+        };
+        let current = Timings {
+            data: vec![
+                get_mock_timing(day!(1), Some(150), Some(100)),
+                get_mock_timing(day!(2), Some(100), Some(100)),
+                get_mock_timing(day!(3), Some(100), Some(100)),
+            ],
+        };
+
+        let changes = compare(&baseline, &current);
+
+        assert_eq!(changes.len(), 3);
+        assert_eq!(changes[0].percent(), 50.0);
+        assert_eq!(changes[1].percent(), -50.0);
+        assert_eq!(changes[2].day, day!(2));
+        assert_eq!(changes[2].percent(), 0.0);
+    }
+
+    #[test]
+    fn compares_averages_without_stats() {
+        let mut baseline = Timings {
+            data: vec![get_mock_timing(day!(1), Some(100), None)],
+        };
+        baseline.data[0].part_1 = Some(PartTiming {
+            nanos: 200.0,
+            samples: None,
+            stats: None,
+            timed_out: false,
+            failed: false,
+            budget: None,
+        });
+        let current = Timings {
+            data: vec![get_mock_timing(day!(1), Some(100), None)],
+        };
+
+        let changes = compare(&baseline, &current);
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].percent(), -50.0);
+    }
+
+    #[test]
+    fn detects_regressions_beyond_threshold() {
+        let change = PartChange {
+            day: day!(1),
+            part: 1,
+            baseline: Duration::from_nanos(100),
+            current: Duration::from_nanos(111),
+            budget_differs: false,
+        };
+
+        assert_eq!(change.is_regression(10.0), true);
+        assert_eq!(change.is_regression(20.0), false);
+    }
+
+    #[test]
+    fn ignores_regressions_with_different_budgets() {
+        let change = PartChange {
+            day: day!(1),
+            part: 1,
+            baseline: Duration::from_nanos(100),
+            current: Duration::from_nanos(200),
+            budget_differs: true,
+        };
+
+        assert_eq!(change.is_regression(10.0), false);
+
+        let lines = format_report(&[change], 10.0);
+        assert!(!lines[1].contains("regression"));
+        assert!(lines[1].contains("+100.0%"));
+        assert!(lines[1].contains("different bench budget"));
+    }
+
+    #[test]
+    fn formats_report() {
+        let changes = [PartChange {
+            day: day!(4),
+            part: 2,
+            baseline: Duration::from_nanos(100),
+            current: Duration::from_nanos(150),
+            budget_differs: false,
+        }];
+
+        let lines = format_report(&changes, 10.0);
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].starts_with("Day 04 Part 2: 100.0ns → 150.0ns"));
+        assert!(lines[1].contains("+50.0% regression"));
+        assert!(!lines[1].contains("different bench budget"));
+    }
+
+    #[test]
+    fn compares_parse_times() {
+        let mut baseline = Timings {
+            data: vec![get_mock_timing(day!(1), Some(100), None)],
+        };
+        let mut current = baseline.clone();
+        for (timings, nanos) in [(&mut baseline, 200), (&mut current, 100)] {
+            timings.data[0].parse = Some(PartTiming::from_stats(BenchStats::from_samples(&[
+                Duration::from_nanos(nanos),
+            ])));
+        }
+
+        let changes = compare(&baseline, &current);
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].percent(), -50.0);
+        assert!(format_report(&changes, 10.0)[1].starts_with("Day 01 Parse: 200.0ns → 100.0ns"));
+    }
+
+    #[test]
+    fn notes_different_budgets() {
+        let mut baseline = Timings {
+            data: vec![get_mock_timing(day!(1), Some(100), Some(100))],
+        };
+        let mut current = baseline.clone();
+        for (timings, budget) in [
+            (&mut baseline, BenchBudget::default()),
+            (&mut current, BenchBudget::QUICK),
+        ] {
+            timings.data[0].part_1.as_mut().unwrap().budget = Some(budget);
+        }
+
+        let changes = compare(&baseline, &current);
+
+        assert_eq!(changes[0].budget_differs, true);
+        // parts without a recorded budget can not be told apart.
+        assert_eq!(changes[1].budget_differs, false);
+        assert!(format_report(&changes, 10.0)[1].contains("different bench budget"));
+    }
+}
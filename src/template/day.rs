@@ -1,49 +1,106 @@
+use std::env;
 use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use crate::template::Year;
+
 #[cfg(feature = "today")]
 use chrono::{Datelike, FixedOffset, Utc};
 
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
 
-/// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// A valid day number of advent (i.e. an integer in range 1 to 25), optionally of a specific [`Year`].
+/// Days without a year belong to the year configured with `AOC_YEAR`.
+///
+/// Events from 2025 on only have 12 days, see [`last_day_of`]. Parsing a [`Day`] checks that it is part of its event.
 ///
 /// # Display
-/// This value displays as a two digit number.
+/// This value displays as a two digit number, prefixed with its year if it has one.
+/// This is the name of the day's files, e.g. `src/bin/08.rs` or `src/bin/2024_08.rs`.
 ///
 /// ```
-/// # use advent_of_code::Day;
+/// # use advent_of_code::template::{Day, Year};
 /// let day = Day::new(8).unwrap();
-/// assert_eq!(day.to_string(), "08")
+/// assert_eq!(day.to_string(), "08");
+/// assert_eq!(day.with_year(Year::new(2024).unwrap()).to_string(), "2024_08");
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Day(u8);
+pub struct Day {
+    year: Option<Year>,
+    day: u8,
+}
 
 impl Day {
     /// Creates a [`Day`] from the provided value if it's in the valid range,
-    /// returns [`None`] otherwise.
+    /// returns [`None`] otherwise. This does not check the range of the event, see [`Day::is_in_event`].
     pub const fn new(day: u8) -> Option<Self> {
         if day == 0 || day > 25 {
             return None;
         }
-        Some(Self(day))
+        Some(Self { year: None, day })
+    }
+
+    /// Returns the same day of the provided year.
+    #[must_use]
+    pub const fn with_year(self, year: Year) -> Self {
+        Self {
+            year: Some(year),
+            day: self.day,
+        }
     }
 
     /// Converts the [`Day`] into an [`u8`].
     pub fn into_inner(self) -> u8 {
-        self.0
+        self.day
+    }
+
+    /// The year of the day, if it has one.
+    pub fn year(self) -> Option<Year> {
+        self.year
+    }
+
+    /// Whether the day is part of its event, i.e. not after [`last_day_of`] its year.
+    pub fn is_in_event(self) -> bool {
+        self.day <= last_day_of(self.year)
+    }
+
+    /// Drops the year of a day of `AOC_YEAR`, see [`Year::normalize`].
+    #[must_use]
+    pub fn normalized(self) -> Self {
+        self.normalized_for(Year::from_env())
+    }
+
+    fn normalized_for(self, configured: Option<Year>) -> Self {
+        Self {
+            year: Year::normalize_for(self.year, configured),
+            ..self
+        }
+    }
+
+    /// The year of the day's puzzle, falling back to `AOC_YEAR` for days without a year.
+    pub fn puzzle_year(self) -> Option<Year> {
+        self.year.or_else(Year::from_env)
+    }
+
+    /// Parses a day like [`FromStr`], but only checks that it is in range 1 to 25.
+    /// Stored data is read with this, so that a changed event range does not make it unreadable.
+    pub fn parse_stored(s: &str) -> Result<Self, DayFromStrError> {
+        let (year, day) = split_year(s)?;
+        let error = DayFromStrError::Day { last_day: 25 };
+        let day = Self::new(day.parse().map_err(|_| error)?).ok_or(error)?;
+        Ok(Self { year, ..day })
     }
 }
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Returns the current day if it's a day of advent in december, `None` otherwise.
     pub fn today() -> Option<Self> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
         let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
+        if today.month() == 12 && today.day() <= u32::from(last_day_of(None)) {
             Self::new(u8::try_from(today.day()).ok()?)
         } else {
             None
@@ -53,19 +110,22 @@ impl Day {
 
 impl Display for Day {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:02}", self.0)
+        match self.year {
+            Some(year) => write!(f, "{year}_{:02}", self.day),
+            None => write!(f, "{:02}", self.day),
+        }
     }
 }
 
 impl PartialEq<u8> for Day {
     fn eq(&self, other: &u8) -> bool {
-        self.0.eq(other)
+        self.day.eq(other)
     }
 }
 
 impl PartialOrd<u8> for Day {
     fn partial_cmp(&self, other: &u8) -> Option<std::cmp::Ordering> {
-        self.0.partial_cmp(other)
+        self.day.partial_cmp(other)
     }
 }
 
@@ -74,40 +134,90 @@ impl PartialOrd<u8> for Day {
 impl FromStr for Day {
     type Err = DayFromStrError;
 
+    /// Parses days like `5`, `05` or, with a year, `2024_05`.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let day = s.parse().map_err(|_| DayFromStrError)?;
-        Self::new(day).ok_or(DayFromStrError)
+        let (year, day) = split_year(s)?;
+        let error = DayFromStrError::Day {
+            last_day: last_day_of(year),
+        };
+        let day = Self::new(day.parse().map_err(|_| error)?).ok_or(error)?;
+        let day = Self { year, ..day };
+        day.is_in_event().then_some(day).ok_or(error)
+    }
+}
+
+/// Split the year off days like `2024_05`.
+fn split_year(s: &str) -> Result<(Option<Year>, &str), DayFromStrError> {
+    match s.split_once('_') {
+        Some((year, day)) => Ok((Some(year.parse().map_err(|_| DayFromStrError::Year)?), day)),
+        None => Ok((None, s)),
     }
 }
 
 /// An error which can be returned when parsing a [`Day`].
-#[derive(Debug)]
-pub struct DayFromStrError;
+#[derive(Debug, Clone, Copy)]
+pub enum DayFromStrError {
+    Year,
+    Day { last_day: u8 },
+}
 
 impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 25")
+        match self {
+            DayFromStrError::Year => f.write_str("expecting a year from 2015 on before the day"),
+            DayFromStrError::Day { last_day } => {
+                write!(f, "expecting a day number between 1 and {last_day}")
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The last day of advent of a year.
+/// Days without a year use `AOC_DAYS` if it is set, or the last day of `AOC_YEAR`.
+pub fn last_day_of(year: Option<Year>) -> u8 {
+    match year {
+        Some(year) => year.last_day(),
+        None => env::var("AOC_DAYS")
+            .ok()
+            .and_then(|x| x.parse().ok())
+            .filter(|x| (1..=25).contains(x))
+            .or_else(|| Year::from_env().map(Year::last_day))
+            .unwrap_or(25),
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent of `AOC_YEAR`, see [`last_day_of`].
 pub fn all_days() -> AllDays {
     AllDays::new()
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent of a year, see [`last_day_of`].
+/// Without a year, it yields the days that belong to `AOC_YEAR`, like [`all_days`].
+pub fn all_days_in(year: Option<Year>) -> AllDays {
+    AllDays {
+        year,
+        current: 1,
+        last: last_day_of(year),
+    }
+}
+
+/// An iterator that yields every day of advent of a year, from the 1st to its last day.
 pub struct AllDays {
+    year: Option<Year>,
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { current: 1 }
+        all_days_in(None)
     }
 }
 
@@ -115,11 +225,14 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
-        let day = Day(self.current);
+        // NOTE: the iterator starts at 1 and the last day is not above 25.
+        let day = Day {
+            year: self.year,
+            day: self.current,
+        };
         self.current += 1;
 
         Some(day)
@@ -128,7 +241,7 @@ impl Iterator for AllDays {
 
 /* -------------------------------------------------------------------------- */
 
-/// Creates a [`Day`] value in a const context.
+/// Creates a [`Day`] value in a const context, optionally of a year: `day!(5)` or `day!(2024, 5)`.
 #[macro_export]
 macro_rules! day {
     ($day:expr) => {
@@ -137,45 +250,101 @@ macro_rules! day {
                 .expect("invalid day number, expecting a value between 1 and 25")
         }
     };
+    ($year:expr, $day:expr) => {
+        const { $crate::day!($day).with_year($crate::year!($year)) }
+    };
 }
 
 /* -------------------------------------------------------------------------- */
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{Day, all_days};
+    use super::{Day, all_days_in, last_day_of};
+    use crate::year;
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
-
-        assert_eq!(iter.next(), Some(Day(1)));
-        assert_eq!(iter.next(), Some(Day(2)));
-        assert_eq!(iter.next(), Some(Day(3)));
-        assert_eq!(iter.next(), Some(Day(4)));
-        assert_eq!(iter.next(), Some(Day(5)));
-        assert_eq!(iter.next(), Some(Day(6)));
-        assert_eq!(iter.next(), Some(Day(7)));
-        assert_eq!(iter.next(), Some(Day(8)));
-        assert_eq!(iter.next(), Some(Day(9)));
-        assert_eq!(iter.next(), Some(Day(10)));
-        assert_eq!(iter.next(), Some(Day(11)));
-        assert_eq!(iter.next(), Some(Day(12)));
-        assert_eq!(iter.next(), Some(Day(13)));
-        assert_eq!(iter.next(), Some(Day(14)));
-        assert_eq!(iter.next(), Some(Day(15)));
-        assert_eq!(iter.next(), Some(Day(16)));
-        assert_eq!(iter.next(), Some(Day(17)));
-        assert_eq!(iter.next(), Some(Day(18)));
-        assert_eq!(iter.next(), Some(Day(19)));
-        assert_eq!(iter.next(), Some(Day(20)));
-        assert_eq!(iter.next(), Some(Day(21)));
-        assert_eq!(iter.next(), Some(Day(22)));
-        assert_eq!(iter.next(), Some(Day(23)));
-        assert_eq!(iter.next(), Some(Day(24)));
-        assert_eq!(iter.next(), Some(Day(25)));
+        let mut iter = all_days_in(Some(year!(2024))).map(|day| Day { year: None, ..day });
+
+        assert_eq!(iter.next(), Some(day!(1)));
+        assert_eq!(iter.next(), Some(day!(2)));
+        assert_eq!(iter.next(), Some(day!(3)));
+        assert_eq!(iter.next(), Some(day!(4)));
+        assert_eq!(iter.next(), Some(day!(5)));
+        assert_eq!(iter.next(), Some(day!(6)));
+        assert_eq!(iter.next(), Some(day!(7)));
+        assert_eq!(iter.next(), Some(day!(8)));
+        assert_eq!(iter.next(), Some(day!(9)));
+        assert_eq!(iter.next(), Some(day!(10)));
+        assert_eq!(iter.next(), Some(day!(11)));
+        assert_eq!(iter.next(), Some(day!(12)));
+        assert_eq!(iter.next(), Some(day!(13)));
+        assert_eq!(iter.next(), Some(day!(14)));
+        assert_eq!(iter.next(), Some(day!(15)));
+        assert_eq!(iter.next(), Some(day!(16)));
+        assert_eq!(iter.next(), Some(day!(17)));
+        assert_eq!(iter.next(), Some(day!(18)));
+        assert_eq!(iter.next(), Some(day!(19)));
+        assert_eq!(iter.next(), Some(day!(20)));
+        assert_eq!(iter.next(), Some(day!(21)));
+        assert_eq!(iter.next(), Some(day!(22)));
+        assert_eq!(iter.next(), Some(day!(23)));
+        assert_eq!(iter.next(), Some(day!(24)));
+        assert_eq!(iter.next(), Some(day!(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_in_year_iterator() {
+        let mut iter = all_days_in(Some(year!(2024)));
+        assert_eq!(iter.next(), Some(day!(2024, 1)));
+        assert_eq!(iter.count(), 24);
+    }
+
+    #[test]
+    fn short_event_iterator() {
+        let mut iter = all_days_in(Some(year!(2025)));
+        assert_eq!(iter.next(), Some(day!(2025, 1)));
+        assert_eq!(iter.last(), Some(day!(2025, 12)));
+    }
+
+    #[test]
+    fn formats_and_parses_days() {
+        assert_eq!(day!(5).to_string(), "05");
+        assert_eq!(day!(2024, 5).to_string(), "2024_05");
+
+        assert_eq!("5".parse::<Day>().unwrap(), day!(5));
+        assert_eq!("05".parse::<Day>().unwrap(), day!(5));
+        assert_eq!("2024_05".parse::<Day>().unwrap(), day!(2024, 5));
+        assert!("26".parse::<Day>().is_err());
+        assert!("2014_05".parse::<Day>().is_err());
+        assert!("2024_".parse::<Day>().is_err());
+    }
+
+    #[test]
+    fn parses_days_of_the_event() {
+        assert_eq!("2024_25".parse::<Day>().unwrap(), day!(2024, 25));
+        assert_eq!("2025_12".parse::<Day>().unwrap(), day!(2025, 12));
+        assert!("2025_13".parse::<Day>().is_err());
+        assert_eq!(Day::parse_stored("2025_13").unwrap().into_inner(), 13);
+        assert!(Day::parse_stored("26").is_err());
+        assert_eq!(last_day_of(Some(year!(2015))), 25);
+        assert_eq!(last_day_of(Some(year!(2025))), 12);
+    }
+
+    #[test]
+    fn normalizes_days_of_the_configured_year() {
+        let day = "2025_05".parse::<Day>().unwrap();
+        assert_eq!(day.normalized_for(Some(year!(2025))), day!(5));
+        assert_eq!(day.normalized_for(Some(year!(2024))), day!(2025, 5));
+        assert_eq!(day.normalized_for(None), day!(2025, 5));
+    }
+
+    #[test]
+    fn orders_days_by_year() {
+        assert!(day!(25) < day!(2015, 1));
+        assert!(day!(2015, 25) < day!(2024, 1));
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,103 @@
+/// Module for named examples, which live in `data/examples/{day}/{name}.txt`.
+///
+/// The expected answers of an example live next to it in `{name}.answers`, one line per part:
+/// ```text
+/// 1: 142
+/// 2: 281
+/// ```
+/// A build script generates a test for every part with an expected answer, see [`crate::example_tests`].
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::template::Day;
+use crate::template::runner::PartOutput;
+
+fn get_examples_dir() -> PathBuf {
+    env::current_dir().unwrap().join("data").join("examples")
+}
+
+fn get_dir(day: Day) -> PathBuf {
+    get_examples_dir().join(day.to_string())
+}
+
+/// The path of an example: the day's example file without a name, otherwise a named example,
+/// falling back to `{day}-{name}.txt` as read by [`crate::template::read_file_part`].
+pub fn get_path(day: Day, name: Option<&str>) -> PathBuf {
+    let Some(name) = name else {
+        return get_examples_dir().join(format!("{day}.txt"));
+    };
+
+    let named = get_dir(day).join(format!("{name}.txt"));
+    if named.exists() {
+        named
+    } else {
+        get_examples_dir().join(format!("{day}-{name}.txt"))
+    }
+}
+
+/// Read a named example of a day.
+#[must_use]
+pub fn read(day: Day, name: &str) -> String {
+    let path = get_dir(day).join(format!("{name}.txt"));
+    fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("could not open example file \"{}\": {e}", path.display()))
+}
+
+/// Read the expected answer of a part of a named example, if present.
+pub fn expected_answer(day: Day, name: &str, part: u8) -> Option<String> {
+    expected_answer_at(&get_dir(day).join(format!("{name}.txt")), part)
+}
+
+/// Read the expected answer of a part from the `.answers` file next to an input file, if present.
+pub fn expected_answer_at(path: &Path, part: u8) -> Option<String> {
+    let answers = fs::read_to_string(path.with_extension("answers")).ok()?;
+    parse_answers(&answers)
+        .into_iter()
+        .find(|(x, _)| *x == part)
+        .map(|(_, answer)| answer)
+}
+
+/// Parse the lines of an answers file, e.g. `1: 142`. Blank lines and lines starting with `#` are ignored.
+pub fn parse_answers(answers: &str) -> Vec<(u8, String)> {
+    answers
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let (part, answer) = line.split_once(':')?;
+            Some((part.trim().parse().ok()?, answer.trim().to_string()))
+        })
+        .collect()
+}
+
+/// Run a part on a named example and assert that it produces the expected answer.
+/// Called by the tests that [`crate::example_tests`] generates.
+pub fn check<T: PartOutput>(day: Day, name: &str, part: u8, func: impl Fn(&str) -> T) {
+    let expected = expected_answer(day, name, part)
+        .unwrap_or_else(|| panic!("example \"{name}\" has no expected answer for part {part}"));
+    let result = func(&read(day, name))
+        .into_output()
+        .unwrap_or_else(|e| panic!("example \"{name}\", part {part} failed: {e}"));
+    assert_eq!(
+        result.as_deref(),
+        Some(expected.as_str()),
+        "example \"{name}\", part {part}"
+    );
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::parse_answers;
+
+    #[test]
+    fn parses_answers() {
+        assert_eq!(
+            parse_answers("# small grid\n1: 142\n\n2:  -7 \ninvalid\n"),
+            vec![(1, "142".into()), (2, "-7".into())]
+        );
+    }
+}
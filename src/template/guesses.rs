@@ -0,0 +1,315 @@
+/// Module that keeps a ledger of wrong answers submitted for a part.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{Error, Write},
+    path::Path,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+use crate::template::submission::Hint;
+
+static GUESSES_DIR: &str = "./data/guesses";
+
+/// A wrong answer that was submitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Guess {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub answer: String,
+    pub hint: Option<Hint>,
+}
+
+impl Guess {
+    pub fn new(answer: &str, hint: Option<Hint>) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |x| x.as_secs());
+
+        Self {
+            timestamp,
+            answer: answer.to_string(),
+            hint,
+        }
+    }
+}
+
+#[must_use]
+pub fn get_path(day: Day, part: u8) -> String {
+    format!("{GUESSES_DIR}/{day}-{part}.jsonl")
+}
+
+/// Append a wrong guess to the guesses of a part.
+pub fn append(day: Day, part: u8, guess: &Guess) -> Result<(), Error> {
+    fs::create_dir_all(Path::new(GUESSES_DIR))?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_path(day, part))?;
+
+    let line = JsonValue::from(guess)
+        .stringify()
+        .expect("a guess can always be serialized");
+
+    writeln!(file, "{line}")
+}
+
+/// Read all wrong guesses of a part, oldest first. If not present, returns no guesses.
+pub fn read(day: Day, part: u8) -> Vec<Guess> {
+    fs::read_to_string(get_path(day, part))
+        .map(|content| parse_guesses(&content))
+        .unwrap_or_default()
+}
+
+fn parse_guesses(content: &str) -> Vec<Guess> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match Guess::from_str(line) {
+            Ok(guess) => Some(guess),
+            Err(e) => {
+                eprintln!("Skipping malformed guess: {e}");
+                None
+            }
+        })
+        .collect()
+}
+
+/// Wrong guesses of a part and the bounds they imply for the correct answer.
+#[derive(Clone, Debug, Default)]
+pub struct Ledger {
+    pub guesses: Vec<Guess>,
+}
+
+/// Reason for refusing to submit an answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Rejection {
+    /// The answer was submitted before and was wrong.
+    AlreadyGuessed,
+    /// The answer is not above a guess that was too low.
+    TooLow { bound: String },
+    /// The answer is not below a guess that was too high.
+    TooHigh { bound: String },
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejection::AlreadyGuessed => write!(f, "it was submitted before and is wrong"),
+            Rejection::TooLow { bound } => write!(f, "it is too low, {bound} was too low already"),
+            Rejection::TooHigh { bound } => {
+                write!(f, "it is too high, {bound} was too high already")
+            }
+        }
+    }
+}
+
+impl Ledger {
+    /// Read the ledger of a part. If not present, returns an empty ledger.
+    pub fn read(day: Day, part: u8) -> Self {
+        Self {
+            guesses: read(day, part),
+        }
+    }
+
+    /// The highest numeric guess that was too low.
+    pub fn lower_bound(&self) -> Option<i128> {
+        self.numeric_guesses(Hint::TooLow).max()
+    }
+
+    /// The lowest numeric guess that was too high.
+    pub fn upper_bound(&self) -> Option<i128> {
+        self.numeric_guesses(Hint::TooHigh).min()
+    }
+
+    /// Check whether submitting `answer` could be correct.
+    pub fn check(&self, answer: &str) -> Result<(), Rejection> {
+        let answer = answer.trim();
+
+        if self.guesses.iter().any(|x| x.answer.trim() == answer) {
+            return Err(Rejection::AlreadyGuessed);
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        if let Some(bound) = self.lower_bound().filter(|bound| value <= *bound) {
+            return Err(Rejection::TooLow {
+                bound: bound.to_string(),
+            });
+        }
+
+        if let Some(bound) = self.upper_bound().filter(|bound| value >= *bound) {
+            return Err(Rejection::TooHigh {
+                bound: bound.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn numeric_guesses(&self, hint: Hint) -> impl Iterator<Item = i128> {
+        self.guesses
+            .iter()
+            .filter(move |x| x.hint == Some(hint))
+            .filter_map(|x| x.answer.trim().parse().ok())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Guess> for JsonValue {
+    fn from(value: &Guess) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "hint".into(),
+            match value.hint {
+                Some(Hint::TooHigh) => JsonValue::String("too_high".into()),
+                Some(Hint::TooLow) => JsonValue::String("too_low".into()),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Guess {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected guess to be a JSON object.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64)
+            .ok_or("Expected guess.timestamp to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .cloned()
+            .ok_or("Expected guess.answer to be a string.")?;
+
+        let hint = match json.get("hint").and_then(|v| v.get::<String>()) {
+            Some(x) if x == "too_high" => Some(Hint::TooHigh),
+            Some(x) if x == "too_low" => Some(Hint::TooLow),
+            _ => None,
+        };
+
+        Ok(Guess {
+            timestamp,
+            answer,
+            hint,
+        })
+    }
+}
+
+impl FromStr for Guess {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not a valid JSON line."))?;
+        Guess::try_from(&json)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Guess, Ledger, Rejection, parse_guesses};
+    use crate::template::submission::Hint;
+
+    #[test]
+    fn roundtrips_guesses() {
+        let guess = Guess {
+            timestamp: 1_765_620_000,
+            answer: "1234".into(),
+            hint: Some(Hint::TooLow),
+        };
+
+        let line = tinyjson::JsonValue::from(&guess).stringify().unwrap();
+        assert_eq!(line.parse::<Guess>().unwrap(), guess);
+    }
+
+    #[test]
+    fn skips_malformed_lines() {
+        let content = [
+            r#"{ "timestamp": 1, "answer": "10", "hint": "too_high" }"#,
+            r#"{ "timestamp": 2 }"#,
+            r#"{ "timestamp": 3, "answer": "abc", "hint": null }"#,
+        ]
+        .join("\n");
+
+        let guesses = parse_guesses(&content);
+
+        assert_eq!(guesses.len(), 2);
+        assert_eq!(guesses[0].hint, Some(Hint::TooHigh));
+        assert_eq!(guesses[1].hint, None);
+    }
+
+    fn guess(answer: &str, hint: Option<Hint>) -> Guess {
+        Guess {
+            timestamp: 0,
+            answer: answer.into(),
+            hint,
+        }
+    }
+
+    #[test]
+    fn computes_bounds() {
+        let ledger = Ledger {
+            guesses: vec![
+                guess("10", Some(Hint::TooLow)),
+                guess("20", Some(Hint::TooLow)),
+                guess("90", Some(Hint::TooHigh)),
+                guess("80", Some(Hint::TooHigh)),
+                guess("50", None),
+            ],
+        };
+
+        assert_eq!(ledger.lower_bound(), Some(20));
+        assert_eq!(ledger.upper_bound(), Some(80));
+    }
+
+    #[test]
+    fn rejects_known_wrong_answers() {
+        let ledger = Ledger {
+            guesses: vec![
+                guess("20", Some(Hint::TooLow)),
+                guess("80", Some(Hint::TooHigh)),
+                guess("50", None),
+                guess("abc", None),
+            ],
+        };
+
+        assert_eq!(ledger.check("50"), Err(Rejection::AlreadyGuessed));
+        assert_eq!(ledger.check("abc"), Err(Rejection::AlreadyGuessed));
+        assert_eq!(
+            ledger.check("15"),
+            Err(Rejection::TooLow { bound: "20".into() })
+        );
+        assert_eq!(
+            ledger.check("100"),
+            Err(Rejection::TooHigh { bound: "80".into() })
+        );
+        assert_eq!(ledger.check("21"), Ok(()));
+        assert_eq!(ledger.check("xyz"), Ok(()));
+    }
+}
@@ -0,0 +1,316 @@
+/// Module that keeps an append-only history of stored benchmark timings.
+use std::{
+    collections::HashMap,
+    env,
+    fs::{self, OpenOptions},
+    io::{Error, Write},
+    process::Command,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+use crate::template::timings::{PartTiming, Timing, Timings};
+
+static HISTORY_FILE_PATH: &str = "./data/timings_history.jsonl";
+
+/// Benchmark times of a single day at a point in time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub commit: Option<String>,
+    pub machine: Option<String>,
+    pub day: Day,
+    /// The parser of the day, if it is timed separately.
+    pub parse_nanos: Option<f64>,
+    pub part_1_nanos: Option<f64>,
+    pub part_2_nanos: Option<f64>,
+}
+
+impl HistoryEntry {
+    pub fn from_timing(
+        timing: &Timing,
+        timestamp: u64,
+        commit: Option<String>,
+        machine: Option<String>,
+    ) -> Self {
+        Self {
+            timestamp,
+            commit,
+            machine,
+            day: timing.day,
+            // a timed out or failed part has no meaningful duration.
+            parse_nanos: timing
+                .parse
+                .filter(PartTiming::is_measured)
+                .map(|x| x.nanos),
+            part_1_nanos: timing
+                .part_1
+                .filter(PartTiming::is_measured)
+                .map(|x| x.nanos),
+            part_2_nanos: timing
+                .part_2
+                .filter(PartTiming::is_measured)
+                .map(|x| x.nanos),
+        }
+    }
+
+    pub fn total_nanos(&self) -> f64 {
+        self.parse_nanos.unwrap_or(0.0)
+            + self.part_1_nanos.unwrap_or(0.0)
+            + self.part_2_nanos.unwrap_or(0.0)
+    }
+}
+
+/// Append an entry for every day of `timings` to the history file.
+pub fn append(timings: &Timings) -> Result<(), Error> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |x| x.as_secs());
+    let commit = get_commit();
+    let machine = get_machine();
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(HISTORY_FILE_PATH)?;
+
+    for timing in &timings.data {
+        let entry = HistoryEntry::from_timing(timing, timestamp, commit.clone(), machine.clone());
+        let line = JsonValue::from(&entry)
+            .stringify()
+            .expect("a history entry can always be serialized");
+        writeln!(file, "{line}")?;
+    }
+
+    Ok(())
+}
+
+/// Read all history entries of a day, oldest first. If not present, returns an empty history.
+pub fn read_for_day(day: Day) -> Vec<HistoryEntry> {
+    let Ok(content) = fs::read_to_string(HISTORY_FILE_PATH) else {
+        return vec![];
+    };
+
+    parse_entries(&content)
+        .into_iter()
+        .filter(|entry| entry.day == day)
+        .collect()
+}
+
+fn parse_entries(content: &str) -> Vec<HistoryEntry> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match HistoryEntry::from_str(line) {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                eprintln!("Skipping malformed history entry: {e}");
+                None
+            }
+        })
+        .collect()
+}
+
+/// Short hash of the current git commit, if available.
+fn get_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let commit = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!commit.is_empty()).then_some(commit)
+}
+
+/// Label of the machine the benchmarks were run on. Uses `AOC_MACHINE` if set, the hostname otherwise.
+fn get_machine() -> Option<String> {
+    if let Ok(machine) = env::var("AOC_MACHINE") {
+        return Some(machine);
+    }
+
+    let output = Command::new("hostname").output().ok()?;
+    let hostname = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!hostname.is_empty()).then_some(hostname)
+}
+
+/// Format a unix timestamp as a UTC date time, e.g. `2025-12-13 10:04`.
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = timestamp / 86_400;
+    let seconds = timestamp % 86_400;
+
+    // see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    #[allow(clippy::cast_possible_wrap)]
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        seconds / 3600,
+        (seconds % 3600) / 60
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn optional_string(value: Option<&String>) -> JsonValue {
+    value.map_or(JsonValue::Null, |x| JsonValue::String(x.clone()))
+}
+
+fn optional_number(value: Option<f64>) -> JsonValue {
+    value.map_or(JsonValue::Null, JsonValue::Number)
+}
+
+impl From<&HistoryEntry> for JsonValue {
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert("commit".into(), optional_string(value.commit.as_ref()));
+        map.insert("machine".into(), optional_string(value.machine.as_ref()));
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("parse_nanos".into(), optional_number(value.parse_nanos));
+        map.insert("part_1_nanos".into(), optional_number(value.part_1_nanos));
+        map.insert("part_2_nanos".into(), optional_number(value.part_2_nanos));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64)
+            .ok_or("Expected entry.timestamp to be a number.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::parse_stored(day).ok())
+            .ok_or("Expected entry.day to be a Day struct.")?;
+
+        let get_string = |key: &str| json.get(key).and_then(|v| v.get::<String>()).cloned();
+        let get_number = |key: &str| json.get(key).and_then(|v| v.get::<f64>()).copied();
+
+        Ok(HistoryEntry {
+            timestamp,
+            commit: get_string("commit"),
+            machine: get_string("machine"),
+            day,
+            parse_nanos: get_number("parse_nanos"),
+            part_1_nanos: get_number("part_1_nanos"),
+            part_2_nanos: get_number("part_2_nanos"),
+        })
+    }
+}
+
+impl FromStr for HistoryEntry {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not a valid JSON line."))?;
+        HistoryEntry::try_from(&json)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{HistoryEntry, format_timestamp, parse_entries};
+    use crate::{
+        day,
+        template::{
+            stats::BenchStats,
+            timings::{PartTiming, Timing},
+        },
+    };
+
+    #[test]
+    fn creates_entries_from_timings() {
+        let part = |nanos: u64| {
+            PartTiming::from_stats(BenchStats::from_samples(&[Duration::from_nanos(nanos)]))
+        };
+        let timing = Timing {
+            day: day!(5),
+            parse: Some(part(50)),
+            part_1: Some(part(100)),
+            part_2: None,
+            total_nanos: 150.0,
+        };
+
+        let entry = HistoryEntry::from_timing(&timing, 42, Some("abc1234".into()), None);
+
+        assert_eq!(entry.day, day!(5));
+        assert_eq!(entry.parse_nanos, Some(50.0));
+        assert_eq!(entry.part_1_nanos, Some(100.0));
+        assert_eq!(entry.part_2_nanos, None);
+        assert_eq!(entry.total_nanos(), 150.0);
+    }
+
+    #[test]
+    fn roundtrips_entries() {
+        let entry = HistoryEntry {
+            timestamp: 1_765_620_000,
+            commit: Some("abc1234".into()),
+            machine: None,
+            day: day!(12),
+            parse_nanos: None,
+            part_1_nanos: Some(1_500.0),
+            part_2_nanos: None,
+        };
+
+        let line = tinyjson::JsonValue::from(&entry).stringify().unwrap();
+        assert_eq!(line.parse::<HistoryEntry>().unwrap(), entry);
+    }
+
+    #[test]
+    fn skips_malformed_lines() {
+        let content = [
+            r#"{ "timestamp": 1, "day": "01", "part_1_nanos": 10, "part_2_nanos": null }"#,
+            "",
+            r#"{ "timestamp": 2 }"#,
+            r#"{ "timestamp": 3, "day": "02", "commit": "abc1234", "machine": "ci" }"#,
+        ]
+        .join("\n");
+
+        let entries = parse_entries(&content);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].part_1_nanos, Some(10.0));
+        assert_eq!(entries[1].machine, Some("ci".into()));
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1_765_620_240), "2025-12-13 10:04");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00");
+    }
+}
@@ -1,68 +1,213 @@
-use std::{env, fs};
+use std::{
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
 
 pub mod aoc_cli;
+#[cfg(feature = "native-client")]
+pub mod aoc_client;
+pub mod backend;
+pub mod budget;
 pub mod commands;
+pub mod compare;
+pub mod examples;
+pub mod record;
+pub mod registry;
 pub mod runner;
+pub mod solution_options;
+pub mod stats;
+pub mod submission;
 
 pub use day::*;
+pub use year::*;
 
+mod answers;
 mod day;
+mod guesses;
+mod history;
+mod puzzle;
 mod readme_benchmarks;
 mod run_multi;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
 /// Helper function that reads a text file to a string.
-#[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, day: Day) -> Result<String, ReadFileError> {
+    read_data_file(folder, format!("{day}.txt"))
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
-#[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file_part(folder: &str, day: Day, part: u8) -> Result<String, ReadFileError> {
+    read_data_file(folder, format!("{day}-{part}.txt"))
+}
+
+/// Reads the puzzle input of a day, which must not be empty.
+pub fn read_puzzle_input(day: Day) -> Result<String, ReadFileError> {
+    let input = read_file("inputs", day)?;
+    if input.trim().is_empty() {
+        return Err(ReadFileError::Empty {
+            path: data_path("inputs", format!("{day}.txt")),
+        });
+    }
+    Ok(input)
+}
+
+fn data_path(folder: &str, file: String) -> PathBuf {
+    Path::new("data").join(folder).join(file)
+}
+
+fn read_data_file(folder: &str, file: String) -> Result<String, ReadFileError> {
+    let path = data_path(folder, file);
+    fs::read_to_string(&path).map_err(|source| match source.kind() {
+        io::ErrorKind::NotFound => ReadFileError::NotFound { path },
+        _ => ReadFileError::Io { path, source },
+    })
+}
+
+/// An error which can be returned when reading a file of the data directory.
+#[derive(Debug)]
+pub enum ReadFileError {
+    NotFound { path: PathBuf },
+    Empty { path: PathBuf },
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl Display for ReadFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadFileError::NotFound { path } => {
+                write!(f, "could not find \"{}\"", path.display())
+            }
+            ReadFileError::Empty { path } => write!(f, "\"{}\" is empty", path.display()),
+            ReadFileError::Io { path, source } => {
+                write!(f, "could not read \"{}\": {source}", path.display())
+            }
+        }
+    }
+}
+
+impl Error for ReadFileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReadFileError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Includes a test for every part of every named example of the current day, see [`examples`].
+/// Use it in the test module of a day, next to `use super::*;`.
+#[macro_export]
+macro_rules! example_tests {
+    () => {
+        include!(concat!(
+            env!("OUT_DIR"),
+            "/examples/",
+            env!("CARGO_BIN_NAME"),
+            ".rs"
+        ));
+    };
 }
 
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// Days of a year other than `AOC_YEAR` name their year first, e.g. `solution!(year = 2024, 5)`.
+///
+/// Days whose parts share a parser can register it with `solution!(5, parse = parse_input)`.
+/// `part_one` and `part_two` then take a reference to the parsed input, and the parser is timed separately.
 #[macro_export]
 macro_rules! solution {
+    (year = $year:expr, $day:expr, parse = $parse:expr) => {
+        $crate::solution!(@parsed $crate::day!($year, $day), $parse);
+    };
+    ($day:expr, parse = $parse:expr) => {
+        $crate::solution!(@parsed $crate::day!($day), $parse);
+    };
+    (year = $year:expr, $day:expr) => {
+        $crate::solution!(@impl $crate::day!($year, $day), [part_one, 1] [part_two, 2]);
+    };
+    (year = $year:expr, $day:expr, 1) => {
+        $crate::solution!(@impl $crate::day!($year, $day), [part_one, 1]);
+    };
+    (year = $year:expr, $day:expr, 2) => {
+        $crate::solution!(@impl $crate::day!($year, $day), [part_two, 2]);
+    };
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!(@impl $crate::day!($day), [part_one, 1] [part_two, 2]);
     };
     ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+        $crate::solution!(@impl $crate::day!($day), [part_one, 1]);
     };
     ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+        $crate::solution!(@impl $crate::day!($day), [part_two, 2]);
     };
 
     (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
-        /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+        $crate::solution!(@day $day);
 
-        #[cfg(feature = "dhat-heap")]
-        #[global_allocator]
-        static ALLOC: dhat::Alloc = dhat::Alloc;
+        /// The runnable parts of this day, collected by the main binary to run solutions in-process.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::Solution = $crate::template::registry::Solution {
+            day: DAY,
+            parts: &[$( ($part, |input, budget| {
+                $crate::template::runner::record_part($func, input, DAY, $part, budget)
+            }), )*],
+        };
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let options = $crate::template::solution_options::SolutionOptions::from_env();
+            let input = read_input(DAY, &options.input);
+            $( run_part($func, &input, DAY, $part, &options); )*
         }
     };
+
+    (@parsed $day:expr, $parse:expr) => {
+        $crate::solution!(@day $day);
+
+        /// The runnable parts of this day, collected by the main binary to run solutions in-process.
+        /// The parts parse the input again without timing it, the parser is timed as its own record.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::Solution = $crate::template::registry::Solution {
+            day: DAY,
+            parts: &[
+                ($crate::template::record::PARSE_PART, |input, budget| {
+                    $crate::template::runner::record_parse($parse, input, DAY, budget)
+                }),
+                (1, |input, budget| {
+                    $crate::template::runner::record_part(part_one, &$parse(input), DAY, 1, budget)
+                }),
+                (2, |input, budget| {
+                    $crate::template::runner::record_part(part_two, &$parse(input), DAY, 2, budget)
+                }),
+            ],
+        };
+
+        fn main() {
+            use $crate::template::runner::*;
+            let options = $crate::template::solution_options::SolutionOptions::from_env();
+            let input = read_input(DAY, &options.input);
+            let parsed = run_parse($parse, &input, DAY, &options);
+            run_part(part_one, &parsed, DAY, 1, &options);
+            run_part(part_two, &parsed, DAY, 2, &options);
+        }
+    };
+
+    (@day $day:expr) => {
+        /// The current day.
+        const DAY: $crate::template::Day = $day;
+
+        #[cfg(feature = "dhat-heap")]
+        #[global_allocator]
+        static ALLOC: dhat::Alloc = dhat::Alloc;
+    };
 }
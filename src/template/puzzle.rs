@@ -0,0 +1,138 @@
+/// Module that extracts examples and their answers from downloaded puzzle descriptions.
+/// Descriptions are markdown, as written by "aoc-cli" or the built-in client.
+use std::fs;
+
+use crate::template::Day;
+
+static PART_TWO_HEADING: &str = "--- Part Two ---";
+
+#[must_use]
+pub fn get_path(day: Day) -> String {
+    format!("data/puzzles/{day}.md")
+}
+
+/// Read the downloaded description of a day, if present.
+pub fn read(day: Day) -> Option<String> {
+    fs::read_to_string(get_path(day)).ok()
+}
+
+/// The description of a single part. Part two is only included once part one has been solved.
+pub fn part(description: &str, part: u8) -> Option<&str> {
+    match (description.find(PART_TWO_HEADING), part) {
+        (Some(index), 1) => Some(&description[..index]),
+        (None, 1) => Some(description),
+        (Some(index), 2) => Some(&description[index..]),
+        _ => None,
+    }
+}
+
+/// The contents of all fenced code blocks of a description, in order.
+pub fn code_blocks(description: &str) -> Vec<String> {
+    let mut blocks = vec![];
+    let mut current: Option<Vec<&str>> = None;
+
+    for line in description.lines() {
+        if line.trim_start().starts_with("```") {
+            match current.take() {
+                Some(lines) => blocks.push(lines.join("\n") + "\n"),
+                None => current = Some(vec![]),
+            }
+        } else if let Some(lines) = current.as_mut() {
+            lines.push(line);
+        }
+    }
+
+    blocks
+}
+
+/// Guess the answer to the example of a part: the last emphasized number in a paragraph
+/// that mentions an example, e.g. "In this example, ... produces *142*."
+pub fn example_answer(description: &str) -> Option<String> {
+    description
+        .split("\n\n")
+        .filter(|paragraph| paragraph.to_lowercase().contains("example"))
+        .filter(|paragraph| !paragraph.trim_start().starts_with("```"))
+        .filter_map(|paragraph| emphasized_numbers(paragraph).last().cloned())
+        .last()
+}
+
+/// Numbers that are emphasized in markdown, e.g. `*142*`, `**142**` or `` `*142*` ``.
+fn emphasized_numbers(paragraph: &str) -> Vec<String> {
+    let text = paragraph.replace('`', "");
+    let segments: Vec<&str> = text.split('*').collect();
+
+    // segments between two asterisks are emphasized, the first and last one are not.
+    segments
+        .iter()
+        .skip(1)
+        .take(segments.len().saturating_sub(2))
+        .filter(|x| is_number(x))
+        .map(|x| x.to_string())
+        .collect()
+}
+
+fn is_number(s: &str) -> bool {
+    let digits = s.strip_prefix('-').unwrap_or(s);
+    !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{code_blocks, example_answer, part};
+
+    const DESCRIPTION: &str = "## --- Day 1: Trebuchet?! ---
+
+The newly-improved calibration document consists of lines of text.
+
+For example:
+
+```
+1abc2
+pqr3stu8vwx
+```
+
+In this example, the calibration values of these lines are `12` and `38`. Adding these together produces `*50*`.
+
+Consider your entire calibration document. What is the sum of all of the calibration values?
+
+## --- Part Two ---
+
+For example:
+
+```
+two1nine
+```
+
+In this example, the calibration value is *29*. Adding these together produces ***29***.
+";
+
+    #[test]
+    fn splits_parts() {
+        assert!(!part(DESCRIPTION, 1).unwrap().contains("two1nine"));
+        assert!(part(DESCRIPTION, 2).unwrap().contains("two1nine"));
+        assert_eq!(part("no part two yet", 2), None);
+    }
+
+    #[test]
+    fn extracts_code_blocks() {
+        assert_eq!(
+            code_blocks(DESCRIPTION),
+            vec!["1abc2\npqr3stu8vwx\n", "two1nine\n"]
+        );
+    }
+
+    #[test]
+    fn extracts_example_answers() {
+        assert_eq!(
+            example_answer(part(DESCRIPTION, 1).unwrap()),
+            Some("50".into())
+        );
+        assert_eq!(
+            example_answer(part(DESCRIPTION, 2).unwrap()),
+            Some("29".into())
+        );
+        assert_eq!(example_answer("What is the *answer*?"), None);
+    }
+}
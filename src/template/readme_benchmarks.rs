@@ -3,7 +3,7 @@
 use std::{fs, io};
 
 use crate::template::Day;
-use crate::template::timings::Timings;
+use crate::template::timings::{PartTiming, Timings};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -52,25 +52,51 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
+fn format_part(part: Option<&PartTiming>) -> String {
+    match part {
+        None => "-".into(),
+        Some(x) if x.timed_out => format!("timed out (> {:.1?})", x.duration()),
+        Some(x) if x.failed => "failed".into(),
+        Some(x) => format!("{:.1?}", x.duration()),
+    }
+}
+
+/// Days of a specific year are labelled with it, e.g. `2024 Day 5`.
+fn format_day(day: Day) -> String {
+    match day.year() {
+        Some(year) => format!("{year} Day {}", day.into_inner()),
+        None => format!("Day {}", day.into_inner()),
+    }
+}
+
+/// The table has a column for parse times if any day times its parser separately.
 fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
     let header = format!("{prefix} Benchmarks");
+    let has_parse = timings.data.iter().any(|t| t.parse.is_some());
+
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new()];
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    if has_parse {
+        lines.push("| Day | Parse | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---: | :---:  |".into());
+    } else {
+        lines.push("| Day | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---:  |".into());
+    }
 
     for timing in timings.data {
         let path = get_path_for_bin(timing.day);
+        let parse = if has_parse {
+            format!(" `{}` |", format_part(timing.parse.as_ref()))
+        } else {
+            String::new()
+        };
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
+            "| [{}]({}) |{parse} `{}` | `{}` |",
+            format_day(timing.day),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            format_part(timing.part_1.as_ref()),
+            format_part(timing.part_2.as_ref())
         ));
     }
 
@@ -89,6 +115,15 @@ fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result
 }
 
 pub fn update(timings: Timings) -> Result<(), Error> {
+    // leave out stale timings of days that are not part of their event, e.g. after changing `AOC_DAYS`.
+    let timings = Timings {
+        data: timings
+            .data
+            .into_iter()
+            .filter(|t| t.day.is_in_event())
+            .collect(),
+    };
+
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
@@ -100,29 +135,53 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{
+        day,
+        template::timings::{PartTiming, Timing, Timings},
+    };
+
+    fn mock_part(nanos: f64) -> PartTiming {
+        PartTiming {
+            nanos,
+            samples: None,
+            stats: None,
+            timed_out: false,
+            failed: false,
+            budget: None,
+        }
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    parse: None,
+                    part_1: Some(mock_part(10_000_000.0)),
+                    part_2: Some(mock_part(20_000_000.0)),
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    parse: None,
+                    part_1: Some(mock_part(30_000_000.0)),
+                    part_2: Some(mock_part(40_000_000.0)),
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
+                    parse: None,
+                    part_1: Some(mock_part(40_000_000.0)),
+                    part_2: Some(mock_part(50_000_000.0)),
                     total_nanos: 9e+10,
                 },
+                Timing {
+                    day: day!(2024, 5),
+                    parse: None,
+                    part_1: Some(mock_part(1_000_000.0)),
+                    part_2: None,
+                    total_nanos: 1e+6,
+                },
             ],
         }
     }
@@ -169,9 +228,10 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/04.rs) | `40.0ms` | `50.0ms` |",
+            "| [2024 Day 5](./src/bin/2024_05.rs) | `1.0ms` | `-` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +240,24 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_parse_times() {
+        let mut timings = get_mock_timings();
+        timings.data[1].parse = Some(mock_part(5_000_000.0));
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 195.0).unwrap();
+
+        let lines: Vec<&str> = s.lines().collect();
+        assert_eq!(lines[3], "| Day | Parse | Part 1 | Part 2 |");
+        assert_eq!(
+            lines[5],
+            "| [Day 1](./src/bin/01.rs) | `-` | `10.0ms` | `20.0ms` |"
+        );
+        assert_eq!(
+            lines[6],
+            "| [Day 2](./src/bin/02.rs) | `5.0ms` | `30.0ms` | `40.0ms` |"
+        );
+    }
 }
@@ -0,0 +1,333 @@
+/// Machine-readable output of solution runs.
+use std::{collections::HashMap, error::Error, fmt::Display, str::FromStr, time::Duration};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, stats::BenchStats};
+
+/// Output format of the solution runner.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable output, the default.
+    #[default]
+    Text,
+    /// One JSON record per solution part.
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = OutputFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(OutputFormatFromStrError),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutputFormat::Text => f.write_str("text"),
+            OutputFormat::Json => f.write_str("json"),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`OutputFormat`].
+#[derive(Debug)]
+pub struct OutputFormatFromStrError;
+
+impl Error for OutputFormatFromStrError {}
+
+impl Display for OutputFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting an output format of either `text` or `json`")
+    }
+}
+
+/// A line of output, written to stdout or stderr.
+#[derive(Clone, Debug, PartialEq)]
+pub enum OutputLine {
+    Stdout(String),
+    Stderr(String),
+}
+
+impl OutputLine {
+    /// A human-readable message, which goes to stderr in JSON mode to keep stdout machine-readable.
+    pub fn message(format: OutputFormat, line: impl Into<String>) -> Self {
+        match format {
+            OutputFormat::Text => OutputLine::Stdout(line.into()),
+            OutputFormat::Json => OutputLine::Stderr(line.into()),
+        }
+    }
+
+    pub fn print(&self) {
+        match self {
+            OutputLine::Stdout(line) => println!("{line}"),
+            OutputLine::Stderr(line) => eprintln!("{line}"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The part number of records that hold the timings of a day's parser, see [`crate::solution`].
+pub const PARSE_PART: u8 = 0;
+
+/// The label of a part in text output, e.g. `Part 1`, or `Parse` for [`PARSE_PART`].
+pub fn part_label(part: u8) -> String {
+    if part == PARSE_PART {
+        "Parse".into()
+    } else {
+        format!("Part {part}")
+    }
+}
+
+/// The result of running a single part of a solution.
+///
+/// Solution binaries print one record per line when invoked with `--format json`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartRecord {
+    pub day: Day,
+    pub part: u8,
+    /// The answer of the part, `None` if it is not implemented yet or failed.
+    pub answer: Option<String>,
+    /// The error message of a part that returned an `Err`.
+    pub error: Option<String>,
+    pub stats: BenchStats,
+    /// The part was stopped after exceeding its timeout, `stats` hold the timeout.
+    pub timed_out: bool,
+    /// The part did not run because an earlier part timed out.
+    pub skipped: bool,
+}
+
+impl PartRecord {
+    pub fn timed_out(day: Day, part: u8, timeout: Duration) -> Self {
+        Self {
+            day,
+            part,
+            answer: None,
+            error: None,
+            stats: BenchStats::from_samples(&[timeout]),
+            timed_out: true,
+            skipped: false,
+        }
+    }
+
+    pub fn skipped(day: Day, part: u8) -> Self {
+        Self {
+            day,
+            part,
+            answer: None,
+            error: None,
+            stats: BenchStats::from_samples(&[Duration::ZERO]),
+            timed_out: false,
+            skipped: true,
+        }
+    }
+
+    /// Whether the record holds the timings of the day's parser instead of a part.
+    pub fn is_parse(&self) -> bool {
+        self.part == PARSE_PART
+    }
+
+    /// The answer of the part, or its error message if it failed.
+    pub fn output(&self) -> Result<Option<&str>, &str> {
+        match &self.error {
+            Some(error) => Err(error),
+            None => Ok(self.answer.as_deref()),
+        }
+    }
+
+    /// Serialize the record to a single line of JSON.
+    pub fn to_json_line(&self) -> String {
+        JsonValue::from(self)
+            .stringify()
+            .expect("a part record can always be serialized")
+    }
+}
+
+impl From<&PartRecord> for JsonValue {
+    fn from(value: &PartRecord) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "error".into(),
+            match &value.error {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        value.stats.write_json(&mut map);
+        map.insert("timed_out".into(), JsonValue::Boolean(value.timed_out));
+        map.insert("skipped".into(), JsonValue::Boolean(value.skipped));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartRecord {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected record to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected record.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|part| *part as u8)
+            .ok_or("Expected record.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected record.answer to be null or string.")?;
+
+        // records written before parts could fail do not have an error.
+        let error = json.get("error").and_then(|v| v.get::<String>()).cloned();
+
+        let timed_out = json
+            .get("timed_out")
+            .and_then(|v| v.get::<bool>())
+            .copied()
+            .unwrap_or(false);
+
+        let skipped = json
+            .get("skipped")
+            .and_then(|v| v.get::<bool>())
+            .copied()
+            .unwrap_or(false);
+
+        Ok(PartRecord {
+            day,
+            part,
+            answer: answer.cloned(),
+            error,
+            stats: BenchStats::read_json(json)?,
+            timed_out,
+            skipped,
+        })
+    }
+}
+
+impl FromStr for PartRecord {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not a valid JSON line."))?;
+        PartRecord::try_from(&json)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{OutputFormat, PartRecord};
+    use crate::{day, template::stats::BenchStats};
+
+    fn get_mock_record(answer: Option<&str>) -> PartRecord {
+        let samples: Vec<Duration> = [70_000, 90_000, 73_000, 74_130, 1_000_000]
+            .into_iter()
+            .map(Duration::from_nanos)
+            .collect();
+
+        PartRecord {
+            day: day!(3),
+            part: 2,
+            answer: answer.map(str::to_string),
+            error: None,
+            stats: BenchStats::from_samples(&samples),
+            timed_out: false,
+            skipped: false,
+        }
+    }
+
+    #[test]
+    fn parses_output_formats() {
+        assert_eq!("text".parse::<OutputFormat>().unwrap(), OutputFormat::Text);
+        assert_eq!("json".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
+        assert!("yaml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn roundtrips_records() {
+        let record = get_mock_record(Some("42"));
+        let parsed: PartRecord = record.to_json_line().parse().unwrap();
+        assert_eq!(parsed, record);
+    }
+
+    #[test]
+    fn roundtrips_records_with_patterns_in_answer() {
+        let record = get_mock_record(Some("@ @ ( ) ms (2s @ 5 samples)\nsecond line"));
+        let line = record.to_json_line();
+        assert_eq!(line.contains('\n'), false);
+        let parsed: PartRecord = line.parse().unwrap();
+        assert_eq!(parsed, record);
+    }
+
+    #[test]
+    fn roundtrips_missing_answers() {
+        let record = get_mock_record(None);
+        let parsed: PartRecord = record.to_json_line().parse().unwrap();
+        assert_eq!(parsed.answer, None);
+    }
+
+    #[test]
+    fn roundtrips_failed_records() {
+        let record = PartRecord {
+            error: Some("invalid digit found in string".into()),
+            ..get_mock_record(None)
+        };
+        let parsed: PartRecord = record.to_json_line().parse().unwrap();
+        assert_eq!(parsed.output(), Err("invalid digit found in string"));
+    }
+
+    #[test]
+    fn roundtrips_timed_out_records() {
+        let record = PartRecord::timed_out(day!(10), 2, Duration::from_secs(30));
+        let parsed: PartRecord = record.to_json_line().parse().unwrap();
+        assert_eq!(parsed.timed_out, true);
+        assert_eq!(parsed.answer, None);
+        assert_eq!(parsed.stats.mean, Duration::from_secs(30));
+    }
+
+    #[test]
+    fn roundtrips_skipped_records() {
+        let record = PartRecord::skipped(day!(10), 2);
+        let parsed: PartRecord = record.to_json_line().parse().unwrap();
+        assert_eq!(parsed.skipped, true);
+        assert_eq!(parsed.timed_out, false);
+    }
+
+    #[test]
+    fn rejects_non_record_lines() {
+        assert!(
+            "Part 1: 42 (74.13ns @ 100000 samples)"
+                .parse::<PartRecord>()
+                .is_err()
+        );
+        assert!(r#"{ "day": "01" }"#.parse::<PartRecord>().is_err());
+    }
+}
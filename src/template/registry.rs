@@ -0,0 +1,23 @@
+/// Registry of solutions that can be run in-process by the main binary.
+///
+/// The `solution!` macro creates a [`Solution`] constant named `SOLUTION` for every day.
+/// A build script includes all scaffolded days as modules of the main binary and collects these constants,
+/// which lets `all` and `time` call each day's parts directly instead of spawning `cargo run` per day.
+use crate::template::Day;
+use crate::template::budget::BenchBudget;
+use crate::template::record::PartRecord;
+
+/// A solution part that runs against an input and reports its answer and timings.
+/// The part is benched within the budget if one is given, and executed once otherwise.
+pub type PartRunner = fn(&str, Option<BenchBudget>) -> PartRecord;
+
+/// The runnable parts of a day's solution.
+pub struct Solution {
+    pub day: Day,
+    pub parts: &'static [(u8, PartRunner)],
+}
+
+/// Find the registered solution of a day.
+pub fn find(solutions: &'static [Solution], day: Day) -> Option<&'static Solution> {
+    solutions.iter().find(|solution| solution.day == day)
+}
@@ -1,51 +1,424 @@
-use std::{collections::HashSet, io};
-
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use std::{
+    collections::{HashMap, HashSet},
+    io, panic,
+    sync::mpsc,
+    time::Duration,
+};
 
-use super::{
-    all_days,
-    timings::{Timing, Timings},
+use crate::template::answers::{self, AnswerCheck};
+use crate::template::budget::BenchBudget;
+use crate::template::record::{OutputFormat, OutputLine, PARSE_PART, PartRecord, part_label};
+use crate::template::registry::{self, Solution};
+use crate::template::runner::{
+    format_duration, format_parse, format_result, format_skipped, format_stats_line, format_timeout,
+};
+use crate::template::{
+    ANSI_BOLD, ANSI_GREEN, ANSI_ITALIC, ANSI_RED, ANSI_RESET, Day, read_puzzle_input,
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+use super::timings::{PartTiming, Timing, Timings};
+
+/// Options of a [`run_multi`] invocation.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RunOptions {
+    pub is_release: bool,
+    pub is_timed: bool,
+    pub format: OutputFormat,
+    /// Number of days to run concurrently. Timed runs always run sequentially.
+    pub jobs: usize,
+    /// Stop parts whose first execution exceeds this duration.
+    pub timeout: Option<Duration>,
+    /// Check answers against the ones recorded in `data/answers`.
+    pub verify: bool,
+    /// Only run this part of every day.
+    pub part: Option<u8>,
+    /// How long and how often parts are benched in timed runs.
+    pub budget: BenchBudget,
+}
 
-    let mut need_space = false;
+/// Results of a [`run_multi`] invocation.
+#[derive(Clone, Debug, Default)]
+pub struct RunSummary {
+    pub timings: Timings,
+    /// Number of parts that matched their recorded answer, if verifying.
+    pub correct: usize,
+    /// Number of parts that did not match their recorded answer, if verifying.
+    pub incorrect: usize,
+}
 
+/// Run a set of days. Days present in `solutions` are run in-process,
+/// all other days are run by invoking their binary with `cargo run`.
+///
+/// With `jobs > 1`, up to `jobs` days run concurrently. The output of each day is buffered
+/// and printed as one block, in day order. Timed runs always run sequentially so that
+/// benchmarks do not interfere with each other.
+///
+/// If `timeout` is set, all days run as child processes that stop parts exceeding it.
+pub fn run_multi(
+    days_to_run: &HashSet<Day>,
+    options: RunOptions,
+    solutions: &'static [Solution],
+) -> RunSummary {
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+    let mut days: Vec<Day> = days_to_run.iter().copied().collect();
+    days.sort_unstable();
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+    let mut summary = RunSummary::default();
+    let mut need_space = false;
+    let is_text = options.format == OutputFormat::Text;
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let mut start_day = || {
+        if is_text && need_space {
+            println!();
+        }
+        need_space = true;
+    };
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
-        });
+    if options.is_timed || options.jobs <= 1 {
+        for day in days {
+            start_day();
+            let run = run_day(day, options, solutions, |line| line.print());
+            finish_day(day, run, options, &mut summary);
+        }
+    } else {
+        for (day, lines, run) in run_parallel(days, options, solutions) {
+            start_day();
+            lines.iter().for_each(OutputLine::print);
+            finish_day(day, run, options, &mut summary);
+        }
+    }
 
-    if is_timed {
-        let timings = Timings { data: timings };
-        let total_millis = timings.total_millis();
+    if options.is_timed && is_text {
+        let total_millis = summary.timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
-    } else {
-        None
+    }
+
+    summary
+}
+
+/// The records of a single day's run.
+struct DayRun {
+    records: Vec<PartRecord>,
+    /// Whether the day ran without failing and reported at least one part.
+    is_solved: bool,
+}
+
+/// Verify the answers of a day if requested and collect its timing if it was solved.
+fn finish_day(day: Day, run: DayRun, options: RunOptions, summary: &mut RunSummary) {
+    if options.verify {
+        let checks = check_answers(&run.records, options.part, |part| answers::read(day, part));
+        for (part, check) in checks {
+            match check {
+                AnswerCheck::Correct => summary.correct += 1,
+                AnswerCheck::Incorrect { .. } | AnswerCheck::Missing { .. } => {
+                    summary.incorrect += 1;
+                }
+                AnswerCheck::Unknown => {}
+            }
+            format_check(part, &check, options.format).print();
+        }
+    }
+
+    if run.is_solved {
+        summary.timings.data.push(timing_from_records(
+            day,
+            &run.records,
+            options.is_timed.then_some(options.budget),
+        ));
+    }
+}
+
+/// Check the answers of a day's records against the ones returned by `read_answer`.
+/// Parts with a recorded answer but without a result, e.g. because they panicked, timed out or
+/// the day could not be run, are checked as [`AnswerCheck::Missing`].
+fn check_answers(
+    records: &[PartRecord],
+    only_part: Option<u8>,
+    read_answer: impl Fn(u8) -> Option<String>,
+) -> Vec<(u8, AnswerCheck)> {
+    (1..=2)
+        .filter(|part| only_part.is_none_or(|x| x == *part))
+        .filter_map(|part| {
+            let expected = read_answer(part);
+            let record = records
+                .iter()
+                .find(|x| x.part == part && !x.timed_out && !x.skipped);
+
+            let check = match (record, expected) {
+                (Some(record), expected) => {
+                    answers::compare(expected.as_deref(), record.answer.as_deref())
+                }
+                (None, Some(expected)) => AnswerCheck::Missing { expected },
+                (None, None) => return None,
+            };
+            Some((part, check))
+        })
+        .collect()
+}
+
+fn format_check(part: u8, check: &AnswerCheck, format: OutputFormat) -> OutputLine {
+    let line = match check {
+        AnswerCheck::Correct => {
+            format!("{ANSI_GREEN}✔{ANSI_RESET} Part {part} matches the recorded answer.")
+        }
+        AnswerCheck::Incorrect { expected } => format!(
+            "{ANSI_RED}✖ Part {part} does not match the recorded answer:{ANSI_RESET} {expected}"
+        ),
+        AnswerCheck::Unknown => {
+            format!("{ANSI_ITALIC}? Part {part} has no recorded answer.{ANSI_RESET}")
+        }
+        AnswerCheck::Missing { expected } => format!(
+            "{ANSI_RED}✖ Part {part} did not report an answer, expected:{ANSI_RESET} {expected}"
+        ),
+    };
+
+    OutputLine::message(format, line)
+}
+
+type DayOutput = (Day, Vec<OutputLine>, DayRun);
+
+/// Run days on a thread pool of `options.jobs` threads. Yields the buffered output of every day
+/// in day order, as soon as it and all days before it have completed.
+fn run_parallel(
+    days: Vec<Day>,
+    options: RunOptions,
+    solutions: &'static [Solution],
+) -> impl Iterator<Item = DayOutput> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(options.jobs)
+        .build()
+        .expect("failed to build thread pool");
+
+    let (sender, receiver) = mpsc::channel::<(usize, DayOutput)>();
+
+    for (index, day) in days.iter().copied().enumerate() {
+        let sender = sender.clone();
+        pool.spawn(move || {
+            let mut lines = vec![];
+            let run = run_day(day, options, solutions, |line| lines.push(line));
+            // the receiver only hangs up once all days have been printed.
+            let _ = sender.send((index, (day, lines, run)));
+        });
+    }
+
+    drop(sender);
+
+    let mut pending: HashMap<usize, DayOutput> = HashMap::new();
+    let mut next = 0;
+
+    std::iter::from_fn(move || {
+        loop {
+            if let Some(output) = pending.remove(&next) {
+                next += 1;
+                return Some(output);
+            }
+            let (index, output) = receiver.recv().ok()?;
+            pending.insert(index, output);
+        }
+    })
+}
+
+/// Run a single day, passing every line of output to `emit`.
+/// The day is solved if it ran without failing and reported at least one part.
+fn run_day(
+    day: Day,
+    options: RunOptions,
+    solutions: &'static [Solution],
+    mut emit: impl FnMut(OutputLine),
+) -> DayRun {
+    let RunOptions {
+        is_release,
+        is_timed,
+        format,
+        timeout,
+        part,
+        budget,
+        ..
+    } = options;
+    let budget = is_timed.then_some(budget);
+    let is_text = format == OutputFormat::Text;
+
+    if is_text {
+        emit(OutputLine::Stdout(format!(
+            "{ANSI_BOLD}Day {day}{ANSI_RESET}"
+        )));
+        emit(OutputLine::Stdout("------".into()));
+    }
+
+    let mut records = vec![];
+
+    // in-process solutions can not be stopped, run them as child processes instead.
+    let solution = registry::find(solutions, day).filter(|_| timeout.is_none());
+
+    let is_solved =
+        match solution {
+            Some(solution) => run_in_process(solution, budget, part, |output| match output {
+                Ok(record) => {
+                    format_record(&record, format)
+                        .into_iter()
+                        .for_each(&mut emit);
+                    records.push(record);
+                }
+                Err(line) => emit(OutputLine::Stderr(line)),
+            }),
+            None => child_commands::run_solution(day, budget, is_release, timeout, part, |line| {
+                match line {
+                    OutputLine::Stdout(line) => {
+                        if let Some(record) = handle_output_line(&line, format, &mut emit) {
+                            records.push(record);
+                        }
+                    }
+                    line => emit(line),
+                }
+            })
+            .unwrap(),
+        };
+
+    // keep the stored timings of a day if it did not report any part, e.g. because of a missing input.
+    let is_solved = is_solved && !records.is_empty();
+    if !is_solved && is_text {
+        emit(OutputLine::Stdout("Not solved.".into()));
+    }
+
+    DayRun { records, is_solved }
+}
+
+/// Run a registered solution in the current process, calling `on_output` with every part record
+/// or error message. Returns `false` if the input of the day could not be read.
+fn run_in_process(
+    solution: &Solution,
+    budget: Option<BenchBudget>,
+    only_part: Option<u8>,
+    mut on_output: impl FnMut(Result<PartRecord, String>),
+) -> bool {
+    let input = match read_puzzle_input(solution.day) {
+        Ok(input) => input,
+        Err(e) => {
+            on_output(Err(format!(
+                "Could not read the input: {e}. Run `cargo download {}` to download it.",
+                solution.day
+            )));
+            // the day is not solved, so that its stored timings are kept.
+            return false;
+        }
+    };
+
+    run_parts(solution, &input, budget, only_part, on_output);
+    true
+}
+
+/// Run the parts of a registered solution on `input`, see [`run_in_process`].
+fn run_parts(
+    solution: &Solution,
+    input: &str,
+    budget: Option<BenchBudget>,
+    only_part: Option<u8>,
+    mut on_output: impl FnMut(Result<PartRecord, String>),
+) {
+    let parts = solution
+        .parts
+        .iter()
+        // the parser runs with either part.
+        .filter(|(part, _)| *part == PARSE_PART || only_part.is_none_or(|x| x == *part));
+
+    for (part, run) in parts {
+        // a panicking solution should not abort the remaining days.
+        match panic::catch_unwind(|| run(input, budget)) {
+            Ok(record) => on_output(Ok(record)),
+            Err(_) => on_output(Err(format!("{}: panicked.", part_label(*part)))),
+        }
+    }
+}
+
+fn format_record(record: &PartRecord, format: OutputFormat) -> Vec<OutputLine> {
+    match format {
+        OutputFormat::Text if record.skipped => {
+            vec![OutputLine::Stdout(format_skipped(&part_label(record.part)))]
+        }
+        OutputFormat::Text if record.timed_out => vec![OutputLine::Stdout(format_timeout(
+            &part_label(record.part),
+            record.stats.mean,
+        ))],
+        OutputFormat::Text => {
+            let result = if record.is_parse() {
+                format_parse(&record.stats)
+            } else {
+                format_result(
+                    record.output(),
+                    &part_label(record.part),
+                    &format_duration(&record.stats.mean, record.stats.samples),
+                )
+            };
+            std::iter::once(result)
+                .chain(format_stats_line(&record.stats))
+                .map(OutputLine::Stdout)
+                .collect()
+        }
+        OutputFormat::Json => vec![OutputLine::Stdout(record.to_json_line())],
+    }
+}
+
+/// Emit a line of solution output and return the part record it contains, if any.
+/// Lines that are not records (e.g. debug output of a solution) are passed through.
+fn handle_output_line(
+    line: &str,
+    format: OutputFormat,
+    emit: &mut impl FnMut(OutputLine),
+) -> Option<PartRecord> {
+    match line.parse::<PartRecord>() {
+        Ok(record) => {
+            format_record(&record, format).into_iter().for_each(emit);
+            Some(record)
+        }
+        Err(_) => {
+            emit(OutputLine::message(format, line));
+            None
+        }
     }
 }
 
+/// Collect the timing of a day from its part records, which were benched within `budget`.
+fn timing_from_records(day: Day, records: &[PartRecord], budget: Option<BenchBudget>) -> Timing {
+    let mut timing = Timing::empty(day);
+
+    for record in records {
+        // parts that are not implemented yet or were skipped have no timing.
+        if record.skipped {
+            continue;
+        }
+        if !record.is_parse()
+            && record.answer.is_none()
+            && record.error.is_none()
+            && !record.timed_out
+        {
+            continue;
+        }
+
+        let part = PartTiming {
+            timed_out: record.timed_out,
+            failed: record.error.is_some(),
+            budget,
+            ..PartTiming::from_stats(record.stats)
+        };
+
+        match record.part {
+            PARSE_PART => timing.parse = Some(part),
+            1 => timing.part_1 = Some(part),
+            2 => timing.part_2 = Some(part),
+            _ => continue,
+        }
+
+        // the duration of a timed out part is only a lower bound, a failed part did not finish.
+        if part.is_measured() {
+            timing.total_nanos += part.nanos;
+        }
+    }
+
+    timing
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
@@ -65,39 +438,64 @@ pub fn get_path_for_bin(day: Day) -> String {
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, which report their results as JSON records.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::{Error, OutputLine, get_path_for_bin};
+    use crate::template::{Day, budget::BenchBudget, runner::TIMED_OUT_EXIT_CODE};
     use std::{
         io::{BufRead, BufReader},
         path::Path,
         process::{Command, Stdio},
+        sync::mpsc,
         thread,
+        time::Duration,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// Run the solution bin for a given day, calling `on_line` for every line it writes.
+    /// The parts are benched if a `budget` is given.
+    /// Returns `false` if the day has not been scaffolded yet or its binary exited with an error.
+    pub fn run_solution(
+        day: Day,
+        budget: Option<BenchBudget>,
+        is_release: bool,
+        timeout: Option<Duration>,
+        part: Option<u8>,
+        mut on_line: impl FnMut(OutputLine),
+    ) -> Result<bool, Error> {
         // skip command invocation for days that have not been scaffolded yet.
         if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+            return Ok(false);
         }
 
         let day_padded = day.to_string();
+        let timeout = timeout.map(|x| format!("{}s", x.as_secs_f64()));
+        let part = part.map(|x| x.to_string());
+        let budget_args = budget.map(|x| x.to_args()).unwrap_or_default();
         let mut args = vec!["run", "--quiet", "--bin", &day_padded];
 
         if is_release {
             args.push("--release");
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
+        args.extend(["--", "--format", "json"]);
+
+        if budget.is_some() {
+            // mirror `--time` flag and the budget to child invocations.
             args.push("--time");
+            args.extend(budget_args.iter().map(String::as_str));
+        }
+
+        if let Some(timeout) = &timeout {
+            // the child enforces the timeout, so compiling it does not count towards it.
+            args.extend(["--timeout", timeout.as_str()]);
+        }
+
+        if let Some(part) = &part {
+            args.extend(["--part", part.as_str()]);
         }
 
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // stderr lines are read on a separate thread and handed over between stdout lines.
 
         let mut cmd = Command::new("cargo")
             .args(&args)
@@ -108,150 +506,233 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        let (sender, receiver) = mpsc::channel();
 
         let thread = thread::spawn(move || {
             stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+                let _ = sender.send(line.unwrap());
             });
         });
 
         for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+            receiver
+                .try_iter()
+                .for_each(|line| on_line(OutputLine::Stderr(line)));
+            on_line(OutputLine::Stdout(line.unwrap()));
         }
 
         thread.join().unwrap();
-        cmd.wait()?;
+        receiver
+            .try_iter()
+            .for_each(|line| on_line(OutputLine::Stderr(line)));
+        // a solution that exits with an error, e.g. because it panicked or rejected an argument,
+        // did not solve the day. One that stopped a timed out part reported all of its parts.
+        let status = cmd.wait()?;
+        Ok(status.success() || status.code() == Some(TIMED_OUT_EXIT_CODE))
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{OutputLine, check_answers, format_record, run_parts, timing_from_records};
+    use crate::{
+        day,
+        template::{
+            answers::AnswerCheck,
+            budget::BenchBudget,
+            record::{OutputFormat, PARSE_PART, PartRecord},
+            registry::Solution,
+            stats::BenchStats,
+        },
+    };
 
-        Ok(output)
+    fn get_mock_record(part: u8, answer: Option<&str>, nanos: u64) -> PartRecord {
+        PartRecord {
+            day: day!(1),
+            part,
+            answer: answer.map(str::to_string),
+            error: None,
+            stats: BenchStats::from_samples(&[Duration::from_nanos(nanos)]),
+            timed_out: false,
+            skipped: false,
+        }
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+    #[test]
+    fn collects_execution_times() {
+        let res = timing_from_records(
+            day!(1),
+            &[
+                get_mock_record(1, Some("0"), 74),
+                get_mock_record(2, Some("10"), 74_130_000),
+            ],
+            Some(BenchBudget::QUICK),
+        );
+        assert_eq!(res.total_nanos, 74_130_074_f64);
+        assert_eq!(res.part_1.unwrap().nanos, 74_f64);
+        assert_eq!(res.part_1.unwrap().samples, Some(1));
+        assert_eq!(res.part_1.unwrap().budget, Some(BenchBudget::QUICK));
+        assert_eq!(res.part_2.unwrap().nanos, 74_130_000_f64);
+        assert_eq!(
+            res.part_2.unwrap().stats.unwrap().median.as_nanos(),
+            74_130_000
+        );
+    }
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+    #[test]
+    fn collects_with_patterns_in_answer() {
+        let res = timing_from_records(
+            day!(1),
+            &[
+                get_mock_record(1, Some("@ @ @ ( ) ms (2s @ 5 samples)"), 2_000_000_000),
+                get_mock_record(2, Some("10s"), 100_000_000),
+            ],
+            None,
+        );
+        assert_eq!(res.total_nanos, 2_100_000_000_f64);
+        assert_eq!(res.part_1.unwrap().nanos, 2_000_000_000_f64);
+        assert_eq!(res.part_2.unwrap().nanos, 100_000_000_f64);
+    }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+    #[test]
+    fn collects_parse_times() {
+        let res = timing_from_records(
+            day!(1),
+            &[
+                get_mock_record(PARSE_PART, None, 50),
+                get_mock_record(1, Some("0"), 100),
+            ],
+            None,
+        );
+        assert_eq!(res.parse.unwrap().nanos, 50_f64);
+        assert_eq!(res.part_1.unwrap().nanos, 100_f64);
+        assert_eq!(res.total_nanos, 150_f64);
+    }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+    #[test]
+    fn collects_missing_parts() {
+        let res = timing_from_records(day!(1), &[], None);
+        assert_eq!(res.total_nanos, 0_f64);
+        assert_eq!(res.part_1.is_none(), true);
+        assert_eq!(res.part_2.is_none(), true);
+    }
 
-                timings.total_nanos += nanos;
-            });
+    #[test]
+    fn formats_records_as_lines() {
+        let record = get_mock_record(1, Some("42"), 100);
+
+        let lines = format_record(&record, OutputFormat::Text);
+        assert_eq!(lines.len(), 1);
+        assert!(matches!(&lines[0], OutputLine::Stdout(line) if line.starts_with("Part 1: ")));
 
-        timings
+        let lines = format_record(&record, OutputFormat::Json);
+        assert_eq!(lines.len(), 1);
+        assert!(
+            matches!(&lines[0], OutputLine::Stdout(line) if line.parse::<PartRecord>().unwrap() == record)
+        );
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    #[test]
+    fn formats_parse_records() {
+        let record = get_mock_record(PARSE_PART, None, 100);
+        let lines = format_record(&record, OutputFormat::Text);
+        assert_eq!(lines, vec![OutputLine::Stdout("Parse: (100.0ns)".into())]);
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    #[test]
+    fn collects_timed_out_parts() {
+        let res = timing_from_records(
+            day!(1),
+            &[
+                get_mock_record(1, Some("1"), 100),
+                PartRecord::timed_out(day!(1), 2, Duration::from_secs(1)),
+            ],
+            None,
+        );
+        assert_eq!(res.total_nanos, 100_f64);
+        assert_eq!(res.part_1.unwrap().timed_out, false);
+        assert_eq!(res.part_2.unwrap().timed_out, true);
+        assert_eq!(res.part_2.unwrap().nanos, 1_000_000_000_f64);
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    #[test]
+    fn collects_skipped_parts() {
+        let res = timing_from_records(
+            day!(1),
+            &[
+                PartRecord::timed_out(day!(1), 1, Duration::from_secs(1)),
+                PartRecord::skipped(day!(1), 2),
+            ],
+            None,
+        );
+        assert_eq!(res.part_1.unwrap().timed_out, true);
+        assert_eq!(res.part_2, None);
     }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
-
-        #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+    #[test]
+    fn checks_answers_of_panicking_parts() {
+        fn solve(_: &str, _: Option<BenchBudget>) -> PartRecord {
+            get_mock_record(1, Some("42"), 1)
         }
-
-        #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+        fn panic(_: &str, _: Option<BenchBudget>) -> PartRecord {
+            panic!("not implemented")
         }
 
-        #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
-        }
+        let solution = Solution {
+            day: day!(1),
+            parts: &[(1, solve), (2, panic)],
+        };
+        let mut records = vec![];
+        run_parts(&solution, "", None, None, |output| {
+            records.extend(output.ok());
+        });
+
+        let checks = check_answers(&records, None, |part| Some(format!("4{part}")));
+        assert_eq!(
+            checks[0],
+            (
+                1,
+                AnswerCheck::Incorrect {
+                    expected: "41".into()
+                }
+            )
+        );
+        assert_eq!(
+            checks[1],
+            (
+                2,
+                AnswerCheck::Missing {
+                    expected: "42".into()
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn checks_answers_of_failed_days() {
+        let checks = check_answers(&[], Some(2), |_| Some("42".into()));
+        assert_eq!(
+            checks,
+            vec![(
+                2,
+                AnswerCheck::Missing {
+                    expected: "42".into()
+                }
+            )]
+        );
+
+        let timed_out = PartRecord::timed_out(day!(1), 1, Duration::from_secs(1));
+        let checks = check_answers(&[timed_out], None, |part| (part == 1).then(|| "42".into()));
+        assert_eq!(
+            checks,
+            vec![(
+                1,
+                AnswerCheck::Missing {
+                    expected: "42".into()
+                }
+            )]
+        );
     }
 }
@@ -1,63 +1,470 @@
 /// Encapsulates code that interacts with solution functions.
+use std::error::Error;
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{Write, stdout};
-use std::process::Output;
+use std::io::{IsTerminal, Read, Write, stdin, stdout};
+use std::path::PathBuf;
+use std::sync::mpsc;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{process, thread};
 
-use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::budget::BenchBudget;
+use crate::template::commands::download;
+use crate::template::guesses::{self, Guess, Ledger};
+use crate::template::record::{OutputFormat, OutputLine, PARSE_PART, PartRecord, part_label};
+use crate::template::solution_options::SolutionOptions;
+use crate::template::stats::BenchStats;
+use crate::template::submission::Verdict;
+use crate::template::{ANSI_BOLD, ANSI_GREEN, ANSI_RED};
+use crate::template::{
+    ANSI_ITALIC, ANSI_RESET, Day, ReadFileError, answers, backend, examples, read_puzzle_input,
+};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+/// Where the solution binary reads its input from, see [`read_input`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input in `data/inputs`.
+    #[default]
+    Puzzle,
+    /// An example, optionally named: `--example [name]`, see [`examples::get_path`].
+    Example(Option<String>),
+    /// A file, or stdin for `-`: `--input <path>`.
+    File(String),
+}
+
+impl InputSource {
+    /// The file the input is read from, if it is not the puzzle input or stdin.
+    fn path(&self, day: Day) -> Option<PathBuf> {
+        match self {
+            InputSource::Puzzle => None,
+            InputSource::Example(name) => Some(examples::get_path(day, name.as_deref())),
+            InputSource::File(path) if path == "-" => None,
+            InputSource::File(path) => Some(PathBuf::from(path)),
+        }
+    }
+}
+
+/// Read the input of a day from the source passed to the solution binary, see [`InputSource`].
+pub fn read_input(day: Day, source: &InputSource) -> String {
+    let (input, name) = match source.path(day) {
+        None if *source == InputSource::Puzzle => return read_or_download_puzzle_input(day),
+        None => {
+            let mut input = String::new();
+            (
+                stdin().read_to_string(&mut input).map(|_| input),
+                "stdin".into(),
+            )
+        }
+        Some(path) => (std::fs::read_to_string(&path), path.display().to_string()),
+    };
+
+    input.unwrap_or_else(|e| {
+        eprintln!("Could not read input from \"{name}\": {e}");
+        process::exit(1);
+    })
+}
+
+/// Read the puzzle input of a day. When it is missing or empty, offer to download it in a terminal,
+/// and otherwise exit with instructions instead of passing an empty input to the solution.
+fn read_or_download_puzzle_input(day: Day) -> String {
+    let error = match read_puzzle_input(day) {
+        Ok(input) => return input,
+        Err(error) => error,
+    };
+
+    eprintln!("{ANSI_RED}Could not read the input of day {day}: {error}.{ANSI_RESET}");
+
+    if !matches!(error, ReadFileError::Io { .. })
+        && stdin().is_terminal()
+        && let Some(input) = offer_download(day)
+    {
+        return input;
+    }
+
+    eprintln!(
+        "Run `cargo download {day}` to download it, or paste your input into \"data/inputs/{day}.txt\"."
+    );
+    process::exit(1);
+}
+
+/// Ask to download the puzzle input of a day via the configured backend, returning it when downloaded.
+fn offer_download(day: Day) -> Option<String> {
+    let backend = backend::from_env().inspect_err(|e| eprintln!("{e}")).ok()?;
+
+    eprint!("Download it via {} now? [Y/n] ", backend.name());
+    let mut answer = String::new();
+    stdin().read_line(&mut answer).ok()?;
+    if !matches!(answer.trim().to_lowercase().as_str(), "" | "y" | "yes") {
+        return None;
+    }
+
+    if let Err(e) = download::download(backend.as_ref(), day) {
+        eprintln!("failed to download puzzle via {}: {e}", backend.name());
+        return None;
+    }
+
+    read_puzzle_input(day)
+        .inspect_err(|e| eprintln!("{ANSI_RED}{e}.{ANSI_RESET}"))
+        .ok()
+}
+
+/// The return type of a solution part: `Option<T>` for parts that may not be implemented yet,
+/// or `Result<T, E>` for parts that can fail, e.g. with `?` on a parse error.
+pub trait PartOutput {
+    /// The answer of the part, or the error message if it failed.
+    fn into_output(self) -> Result<Option<String>, String>;
+}
+
+impl<T: Display> PartOutput for Option<T> {
+    fn into_output(self) -> Result<Option<String>, String> {
+        Ok(self.map(|x| x.to_string()))
+    }
+}
+
+impl<T: Display, E: Display> PartOutput for Result<T, E> {
+    fn into_output(self) -> Result<Option<String>, String> {
+        self.map(|x| Some(x.to_string())).map_err(|e| e.to_string())
+    }
+}
+
+pub fn run_part<I: Copy, T: PartOutput>(
+    func: impl Fn(I) -> T,
+    input: I,
+    day: Day,
+    part: u8,
+    options: &SolutionOptions,
+) {
+    if !options.runs_part(part) {
+        return;
+    }
+
+    let part_str = part_label(part);
+    let format = options.format;
+    let is_text = format == OutputFormat::Text;
+
+    let (output, timers) = run_timed(
+        |input| func(input).into_output(),
+        input,
+        options,
+        is_text,
+        |timeout| print_timeout(day, part, options, timeout),
+        |output| {
+            if is_text {
+                print_result(to_refs(output), &part_str, "");
+            }
+        },
+    );
+
+    let stats = BenchStats::from_samples(&timers);
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let expected = options
+        .input
+        .path(day)
+        .and_then(|path| examples::expected_answer_at(&path, part));
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let record = PartRecord {
+        day,
+        part,
+        answer: output.clone().ok().flatten(),
+        error: output.err(),
+        stats,
+        timed_out: false,
+        skipped: false,
+    };
+
+    match format {
+        OutputFormat::Text => {
+            print_result(
+                record.output(),
+                &part_str,
+                &format_duration(&record.stats.mean, record.stats.samples),
+            );
+            print_stats(&record.stats);
+        }
+        OutputFormat::Json => println!("{}", record.to_json_line()),
+    }
+
+    if let Some(expected) = &expected {
+        OutputLine::message(format, format_expected(record.answer.as_deref(), expected)).print();
+    }
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    if let Some(answer) = record.answer
+        && let Some(verdict) = submit_result(&answer, day, part, options)
+    {
+        handle_verdict(verdict, day, part, &answer, format);
+    }
+}
+
+/// Run the parser of a day that shares it between its parts, see [`crate::solution`].
+/// The parser is timed like a part and reported as a record of [`PARSE_PART`],
+/// so that the durations of the parts do not include parsing. Returns the parsed input.
+pub fn run_parse<T>(
+    func: impl Fn(&str) -> T,
+    input: &str,
+    day: Day,
+    options: &SolutionOptions,
+) -> T {
+    let format = options.format;
+    let is_text = format == OutputFormat::Text;
+
+    let (parsed, timers) = run_timed(
+        func,
+        input,
+        options,
+        is_text,
+        |timeout| print_timeout(day, PARSE_PART, options, timeout),
+        |_| {
+            if is_text {
+                print!("{}:", part_label(PARSE_PART));
+            }
+        },
+    );
+
+    let record = PartRecord {
+        day,
+        part: PARSE_PART,
+        answer: None,
+        error: None,
+        stats: BenchStats::from_samples(&timers),
+        timed_out: false,
+        skipped: false,
+    };
+
+    match format {
+        OutputFormat::Text => {
+            print!("\r");
+            println!("{}", format_parse(&record.stats));
+            print_stats(&record.stats);
+        }
+        OutputFormat::Json => println!("{}", record.to_json_line()),
+    }
+
+    parsed
+}
+
+/// Print the result of a part that exceeded its timeout, and of the parts after it that are skipped,
+/// since the process exits to stop the timed out part.
+fn print_timeout(day: Day, part: u8, options: &SolutionOptions, timeout: Duration) {
+    let skipped = (part + 1..=2).filter(|x| options.runs_part(*x));
+
+    match options.format {
+        OutputFormat::Text => {
+            print!("\r");
+            println!("{}", format_timeout(&part_label(part), timeout));
+            for part in skipped {
+                println!("{}", format_skipped(&part_label(part)));
+            }
+        }
+        OutputFormat::Json => {
+            println!(
+                "{}",
+                PartRecord::timed_out(day, part, timeout).to_json_line()
+            );
+            for part in skipped {
+                println!("{}", PartRecord::skipped(day, part).to_json_line());
+            }
+        }
+    }
+}
+
+fn to_refs(output: &Result<Option<String>, String>) -> Result<Option<&str>, &str> {
+    output
+        .as_ref()
+        .map(Option::as_deref)
+        .map_err(String::as_str)
+}
+
+/// Print the verdict of a submission and keep track of correct and wrong answers.
+fn handle_verdict(
+    verdict: Result<Verdict, Box<dyn Error>>,
+    day: Day,
+    part: u8,
+    answer: &str,
+    format: OutputFormat,
+) {
+    let verdict = match verdict {
+        Ok(verdict) => verdict,
+        Err(e) => {
+            eprintln!("failed to submit answer: {e}");
+            return;
+        }
+    };
+
+    OutputLine::message(format, verdict.summary()).print();
+
+    match verdict {
+        Verdict::Correct => record_answer(day, part, answer, format),
+        Verdict::Incorrect { hint, .. } => {
+            if let Err(e) = guesses::append(day, part, &Guess::new(answer, hint)) {
+                eprintln!("Failed to record wrong guess: {e}");
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Store an accepted answer so `cargo verify` can check the solution against it later.
+fn record_answer(day: Day, part: u8, answer: &str, format: OutputFormat) {
+    match answers::store(day, part, answer) {
+        Ok(()) => OutputLine::message(
+            format,
+            format!("Recorded answer in \"{}\".", answers::get_path(day, part)),
+        )
+        .print(),
+        Err(e) => eprintln!("Failed to record answer: {e}"),
+    }
+}
+
+/// Run a solution part without printing anything and return its result as a record.
+/// This is used to run solutions in-process, see [`crate::template::registry`].
+/// Timeouts are not supported here, as a thread running a solution can not be stopped.
+/// The part is benched if a `budget` is given, and executed once otherwise.
+pub fn record_part<I: Copy, T: PartOutput>(
+    func: impl Fn(I) -> T,
+    input: I,
+    day: Day,
+    part: u8,
+    budget: Option<BenchBudget>,
+) -> PartRecord {
+    let (output, timers) = run_timed(
+        |input| func(input).into_output(),
+        input,
+        &record_options(budget),
+        false,
+        |_| {},
+        |_| {},
+    );
+
+    PartRecord {
+        day,
+        part,
+        answer: output.clone().ok().flatten(),
+        error: output.err(),
+        stats: BenchStats::from_samples(&timers),
+        timed_out: false,
+        skipped: false,
+    }
+}
+
+/// Run the parser of a day without printing anything and return its timings as a record, see [`run_parse`].
+pub fn record_parse<T>(
+    func: impl Fn(&str) -> T,
+    input: &str,
+    day: Day,
+    budget: Option<BenchBudget>,
+) -> PartRecord {
+    let (_, timers) = run_timed(func, input, &record_options(budget), false, |_| {}, |_| {});
+
+    PartRecord {
+        day,
+        part: PARSE_PART,
+        answer: None,
+        error: None,
+        stats: BenchStats::from_samples(&timers),
+        timed_out: false,
+        skipped: false,
+    }
+}
+
+fn record_options(budget: Option<BenchBudget>) -> SolutionOptions {
+    SolutionOptions {
+        is_timed: budget.is_some(),
+        budget: budget.unwrap_or_default(),
+        ..SolutionOptions::default()
     }
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///  2. in release, the function is benched within its budget, see [`BenchBudget`].
+///     By default, that is approx. 1 second of execution time or 10 samples, whatever takes longer,
+///     and a tenth of the samples is run up front as warm-up and discarded.
+///
+/// If a timeout is set and the first execution exceeds it, `on_timeout` is called and the process exits
+/// with [`TIMED_OUT_EXIT_CODE`].
+/// Benchmark iterations are not subject to the timeout.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
+    options: &SolutionOptions,
+    show_progress: bool,
+    on_timeout: impl FnOnce(Duration) + Send,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = {
+) -> (T, Vec<Duration>) {
+    let (result, base_time) = {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        let run = || {
+            let timer = Instant::now();
+            let result = func(input);
+            (result, timer.elapsed())
+        };
+
+        match options.timeout {
+            Some(timeout) => run_with_timeout(run, timeout, on_timeout),
+            None => run(),
+        }
     };
-    let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let timers = if options.is_timed {
+        bench(func, input, &base_time, &options.budget, show_progress)
     } else {
-        (base_time, 1)
+        vec![base_time]
     };
 
-    (result, run.0, run.1)
+    (result, timers)
+}
+
+/// Exit status of a solution binary that stopped a part after it exceeded its timeout.
+pub const TIMED_OUT_EXIT_CODE: i32 = 124;
+
+/// Run `func` while a watchdog thread waits for it. If it does not finish within `timeout`,
+/// `on_timeout` is called and the process exits, which is the only way to stop the running solution.
+/// `func` runs on the current thread, so neither it nor its input have to be sent to another thread.
+fn run_with_timeout<T>(
+    func: impl FnOnce() -> T,
+    timeout: Duration,
+    on_timeout: impl FnOnce(Duration) + Send,
+) -> T {
+    thread::scope(|scope| {
+        let (sender, receiver) = mpsc::channel::<()>();
+
+        scope.spawn(move || {
+            // the sender is dropped once `func` returns or panics.
+            if let Err(mpsc::RecvTimeoutError::Timeout) = receiver.recv_timeout(timeout) {
+                on_timeout(timeout);
+                let _ = stdout().flush();
+                process::exit(TIMED_OUT_EXIT_CODE);
+            }
+        });
+
+        let result = func();
+        drop(sender);
+        result
+    })
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    budget: &BenchBudget,
+    show_progress: bool,
+) -> Vec<Duration> {
+    if show_progress {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    let bench_iterations = budget.iterations(*base_time);
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    // warm up caches and branch predictors before measuring, these runs are discarded.
+    for _ in 0..budget.warm_up_iterations(bench_iterations) {
+        black_box(func(black_box(input)));
+    }
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -67,22 +474,33 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    timers
+}
+
+/// Summarize benchmark statistics of a part, e.g. ` median 73.0µs · p5 70.0µs · p95 90.0µs`.
+pub(crate) fn format_stats(stats: &BenchStats) -> String {
+    let mut str = format!(
+        "median {:.1?} · p5 {:.1?} · p95 {:.1?} · min {:.1?} · max {:.1?} · σ {:.1?}",
+        stats.median, stats.p5, stats.p95, stats.min, stats.max, stats.std_dev
+    );
+    if stats.outliers > 0 {
+        str.push_str(&format!(" · {} outliers rejected", stats.outliers));
+    }
+    str
+}
+
+/// Print a part's benchmark statistics below its result if it was benched.
+pub(crate) fn print_stats(stats: &BenchStats) {
+    if let Some(line) = format_stats_line(stats) {
+        println!("{line}");
+    }
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+pub(crate) fn format_stats_line(stats: &BenchStats) -> Option<String> {
+    (stats.samples > 1).then(|| format!("  {ANSI_ITALIC}{}{ANSI_RESET}", format_stats(stats)))
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
+pub(crate) fn format_duration(duration: &Duration, samples: u128) -> String {
     if samples == 1 {
         format!(" ({duration:.1?})")
     } else {
@@ -90,78 +508,152 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+pub(crate) fn print_result(output: Result<Option<&str>, &str>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
-    match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
-                }
+    if !is_intermediate_result {
+        print!("\r");
+        println!("{}", format_result(output, part, duration_str));
+        return;
+    }
+
+    match output {
+        Ok(Some(result)) => {
+            if result.contains('\n') {
+                print!("{part}: ▼ ");
             } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                }
+                print!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}");
             }
         }
-        None => {
-            if is_intermediate_result {
-                print!("{part}: ✖");
+        Ok(None) => {
+            print!("{part}: ✖");
+        }
+        Err(_) => {
+            print!("{part}: {ANSI_RED}failed{ANSI_RESET}");
+        }
+    }
+}
+
+/// Format the final result of a part, multi-line results are printed below the part label.
+/// Parts that are not implemented show as `✖`, parts that failed show their error.
+pub(crate) fn format_result(
+    output: Result<Option<&str>, &str>,
+    part: &str,
+    duration_str: &str,
+) -> String {
+    match output {
+        Ok(Some(result)) => {
+            if result.contains('\n') {
+                format!("{part}: ▼ {duration_str}\n{result}")
             } else {
-                print!("\r");
-                println!("{part}: ✖             ");
+                format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}")
             }
         }
+        Ok(None) => format!("{part}: ✖             "),
+        Err(error) => format!("{part}: {ANSI_RED}failed: {error}{ANSI_RESET}{duration_str}"),
+    }
+}
+
+/// Format the timings of a day's parser, e.g. `Parse: (1.2µs @ 100 samples)`.
+pub(crate) fn format_parse(stats: &BenchStats) -> String {
+    format!(
+        "{}:{}",
+        part_label(PARSE_PART),
+        format_duration(&stats.mean, stats.samples)
+    )
+}
+
+/// Format a part that did not run because an earlier part exceeded its timeout.
+pub(crate) fn format_skipped(part: &str) -> String {
+    format!("{part}: {ANSI_ITALIC}skipped after a timeout{ANSI_RESET}")
+}
+
+/// Format a part that was stopped because it exceeded its timeout.
+pub(crate) fn format_timeout(part: &str, timeout: Duration) -> String {
+    format!("{part}: {ANSI_RED}timed out{ANSI_RESET} (> {timeout:.1?})")
+}
+
+/// Parse a timeout like `500ms`, `30s`, `2m` or `1.5` (seconds).
+pub fn parse_timeout(s: &str) -> Result<Duration, TimeoutFromStrError> {
+    let s = s.trim();
+
+    let (value, factor) = if let Some(value) = s.strip_suffix("ms") {
+        (value, 0.001)
+    } else if let Some(value) = s.strip_suffix('s') {
+        (value, 1.0)
+    } else if let Some(value) = s.strip_suffix('m') {
+        (value, 60.0)
+    } else {
+        (s, 1.0)
+    };
+
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|x| x.is_finite() && *x > 0.0)
+        .map(|x| Duration::from_secs_f64(x * factor))
+        .ok_or(TimeoutFromStrError)
+}
+
+/// An error which can be returned when parsing a timeout.
+#[derive(Debug)]
+pub struct TimeoutFromStrError;
+
+impl Error for TimeoutFromStrError {}
+
+impl Display for TimeoutFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a positive timeout like `500ms`, `30s` or `2m`")
+    }
+}
+
+/// Compare a result to the expected answer of its input.
+fn format_expected(answer: Option<&str>, expected: &str) -> String {
+    match answer {
+        Some(answer) if answer == expected => {
+            format!("  {ANSI_GREEN}✔ matches the expected answer.{ANSI_RESET}")
+        }
+        _ => format!("  {ANSI_RED}✖ expected {expected}.{ANSI_RESET}"),
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+/// Try to submit one part of the solution if:
+///  1. it was passed with `--submit`.
+///  2. the result was computed from the puzzle input.
+///  3. a puzzle backend is available, see [`crate::template::backend`].
 fn submit_result<T: Display>(
     result: T,
     day: Day,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
-
-    if !args.contains(&"--submit".into()) {
+    options: &SolutionOptions,
+) -> Option<Result<Verdict, Box<dyn Error>>> {
+    if options.submit_part != Some(part) {
         return None;
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    }
+    let answer = result.to_string();
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
-
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    };
+    if options.input != InputSource::Puzzle {
+        eprintln!("Not submitting {answer}: it was not computed from the puzzle input.");
+        return None;
+    }
 
-    if part_submit != part {
+    if let Err(rejection) = Ledger::read(day, part).check(&answer)
+        && !options.force
+    {
+        eprintln!("Not submitting {answer}: {rejection}. Pass --force to submit anyway.");
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
+    let backend = backend::from_env().unwrap_or_else(|e| {
+        eprintln!("{e}");
         process::exit(1);
-    }
+    });
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    OutputLine::message(
+        options.format,
+        format!("Submitting result via {}...", backend.name()),
+    )
+    .print();
+    Some(backend.submit(day, part, &answer))
 }
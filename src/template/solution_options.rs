@@ -0,0 +1,171 @@
+/// Options of a solution binary, passed by `cargo solve` and the runners of `all` and `time`.
+///
+/// The `solution!` macro parses these once in the binary's `main`, see [`SolutionOptions::from_env`].
+use std::{env, error::Error, ffi::OsString, process, time::Duration};
+
+use crate::template::budget::BenchBudget;
+use crate::template::record::OutputFormat;
+use crate::template::runner::{InputSource, parse_timeout};
+
+/// The parsed arguments of a solution binary.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SolutionOptions {
+    /// Bench the parts instead of running them once: `--time`.
+    pub is_timed: bool,
+    /// Submit the answer of this part: `--submit <part>`.
+    pub submit_part: Option<u8>,
+    /// Submit even if the answer was already rejected: `--force`.
+    pub force: bool,
+    /// Where the input is read from: `--example [name]` or `--input <path>`.
+    pub input: InputSource,
+    /// `--format <text|json>`.
+    pub format: OutputFormat,
+    /// Stop parts whose first execution exceeds this duration: `--timeout <duration>`.
+    pub timeout: Option<Duration>,
+    /// Only run this part: `--part <part>`.
+    pub part: Option<u8>,
+    /// How long and how often parts are benched, see [`BenchBudget::from_args`].
+    pub budget: BenchBudget,
+}
+
+impl SolutionOptions {
+    /// Parse the arguments of the current process, exiting with an error message if they are invalid.
+    pub fn from_env() -> Self {
+        Self::parse(env::args_os().skip(1).collect()).unwrap_or_else(|e| {
+            eprintln!("Error: {e}");
+            process::exit(1);
+        })
+    }
+
+    pub fn parse(args: Vec<OsString>) -> Result<Self, Box<dyn Error>> {
+        let mut args = pico_args::Arguments::from_vec(with_example_value(args));
+
+        let options = Self {
+            is_timed: args.contains("--time"),
+            submit_part: args.opt_value_from_fn("--submit", parse_part)?,
+            force: args.contains("--force"),
+            input: parse_input_source(&mut args)?,
+            format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+            timeout: args.opt_value_from_fn("--timeout", parse_timeout)?,
+            part: args.opt_value_from_fn("--part", parse_part)?,
+            budget: BenchBudget::from_args(&mut args)?,
+        };
+
+        let remaining = args.finish();
+        if !remaining.is_empty() {
+            eprintln!("Warning: unknown argument(s): {remaining:?}.");
+        }
+
+        Ok(options)
+    }
+
+    /// Whether a part should run, see [`SolutionOptions::part`].
+    pub fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|x| x == part)
+    }
+}
+
+/// Parse a part number, which is either 1 or 2.
+pub fn parse_part(s: &str) -> Result<u8, String> {
+    match s.parse() {
+        Ok(part @ (1 | 2)) => Ok(part),
+        _ => Err("expecting part 1 or 2".into()),
+    }
+}
+
+/// `--example` takes an optional name, give it an empty one if it is last or followed by another option.
+pub fn with_example_value(mut args: Vec<OsString>) -> Vec<OsString> {
+    if let Some(index) = args.iter().position(|x| x == "--example") {
+        let has_value = args
+            .get(index + 1)
+            .and_then(|x| x.to_str())
+            .is_some_and(|x| !x.starts_with('-'));
+        if !has_value {
+            args.insert(index + 1, OsString::new());
+        }
+    }
+
+    args
+}
+
+/// Parse `--example [name]` or `--input <path>`, where `-` reads the input from stdin.
+/// Expects the arguments to have been prepared with [`with_example_value`].
+pub fn parse_input_source(args: &mut pico_args::Arguments) -> Result<InputSource, Box<dyn Error>> {
+    let example: Option<String> = args.opt_value_from_str("--example")?;
+    let input: Option<String> = args.opt_value_from_str("--input")?;
+
+    match (example, input) {
+        (Some(_), Some(_)) => Err("`--example` and `--input` can not be combined.".into()),
+        (Some(name), None) => Ok(InputSource::Example(Some(name).filter(|x| !x.is_empty()))),
+        (None, Some(path)) => Ok(InputSource::File(path)),
+        (None, None) => Ok(InputSource::Puzzle),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::SolutionOptions;
+    use crate::template::{budget::BenchBudget, record::OutputFormat, runner::InputSource};
+
+    fn parse(args: &str) -> SolutionOptions {
+        SolutionOptions::parse(args.split_whitespace().map(Into::into).collect()).unwrap()
+    }
+
+    #[test]
+    fn parses_defaults() {
+        assert_eq!(parse(""), SolutionOptions::default());
+    }
+
+    #[test]
+    fn parses_options() {
+        let options = parse("--time --submit 2 --force --format json --timeout 2s --iterations 50");
+        assert_eq!(
+            options,
+            SolutionOptions {
+                is_timed: true,
+                submit_part: Some(2),
+                force: true,
+                format: OutputFormat::Json,
+                timeout: Some(Duration::from_secs(2)),
+                budget: BenchBudget {
+                    min_iterations: 50,
+                    max_iterations: 50,
+                    ..BenchBudget::default()
+                },
+                ..SolutionOptions::default()
+            }
+        );
+    }
+
+    #[test]
+    fn parses_input_sources() {
+        assert_eq!(parse("--example").input, InputSource::Example(None));
+        assert_eq!(parse("--example --time").input, InputSource::Example(None));
+        assert_eq!(
+            parse("--example small").input,
+            InputSource::Example(Some("small".into()))
+        );
+        assert_eq!(parse("--input -").input, InputSource::File("-".into()));
+    }
+
+    #[test]
+    fn selects_parts() {
+        assert!(parse("").runs_part(2));
+        assert!(parse("--part 1").runs_part(1));
+        assert!(!parse("--part 1").runs_part(2));
+    }
+
+    #[test]
+    fn rejects_invalid_options() {
+        let parse =
+            |args: &str| SolutionOptions::parse(args.split_whitespace().map(Into::into).collect());
+        assert!(parse("--part 3").is_err());
+        assert!(parse("--submit").is_err());
+        assert!(parse("--format yaml").is_err());
+        assert!(parse("--example a --input b").is_err());
+    }
+}
@@ -0,0 +1,202 @@
+/// Summary statistics of benchmark samples.
+use std::{collections::HashMap, time::Duration};
+use tinyjson::JsonValue;
+
+/// Statistics over the measured durations of a solution part.
+///
+/// Outliers outside of Tukey's fences (1.5 × the interquartile range) are rejected
+/// before computing the statistics, `samples` counts all measured durations.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BenchStats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub p5: Duration,
+    pub p95: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+    pub samples: u128,
+    pub outliers: u128,
+}
+
+impl BenchStats {
+    /// Summarize a non-empty set of samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "expected at least one sample");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let q1 = percentile(&sorted, 25.0);
+        let q3 = percentile(&sorted, 75.0);
+        let fence = (q3 - q1) * 3 / 2;
+        let (low, high) = (q1.saturating_sub(fence), q3 + fence);
+
+        let kept: Vec<Duration> = sorted
+            .iter()
+            .copied()
+            .filter(|x| (low..=high).contains(x))
+            .collect();
+
+        let mean = kept.iter().map(Duration::as_nanos).sum::<u128>() / kept.len() as u128;
+        #[allow(clippy::cast_possible_truncation)]
+        let mean = Duration::from_nanos(mean as u64);
+
+        Self {
+            mean,
+            median: percentile(&kept, 50.0),
+            p5: percentile(&kept, 5.0),
+            p95: percentile(&kept, 95.0),
+            min: kept[0],
+            max: kept[kept.len() - 1],
+            std_dev: std_dev(&kept, mean),
+            samples: samples.len() as u128,
+            outliers: (samples.len() - kept.len()) as u128,
+        }
+    }
+
+    pub(crate) fn write_json(&self, map: &mut HashMap<String, JsonValue>) {
+        map.insert("nanos".into(), nanos_to_json(self.mean));
+        map.insert("samples".into(), JsonValue::Number(self.samples as f64));
+        map.insert("min_nanos".into(), nanos_to_json(self.min));
+        map.insert("max_nanos".into(), nanos_to_json(self.max));
+        map.insert("median_nanos".into(), nanos_to_json(self.median));
+        map.insert("p5_nanos".into(), nanos_to_json(self.p5));
+        map.insert("p95_nanos".into(), nanos_to_json(self.p95));
+        map.insert("std_dev_nanos".into(), nanos_to_json(self.std_dev));
+        map.insert("outliers".into(), JsonValue::Number(self.outliers as f64));
+    }
+
+    pub(crate) fn read_json(json: &HashMap<String, JsonValue>) -> Result<Self, String> {
+        Ok(Self {
+            mean: nanos_from_json(json, "nanos")?,
+            median: nanos_from_json(json, "median_nanos")?,
+            p5: nanos_from_json(json, "p5_nanos")?,
+            p95: nanos_from_json(json, "p95_nanos")?,
+            min: nanos_from_json(json, "min_nanos")?,
+            max: nanos_from_json(json, "max_nanos")?,
+            std_dev: nanos_from_json(json, "std_dev_nanos")?,
+            samples: count_from_json(json, "samples")?,
+            outliers: count_from_json(json, "outliers")?,
+        })
+    }
+}
+
+/// Nearest-rank percentile of sorted samples.
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let index = ((p / 100.0) * (sorted.len() - 1) as f64).round() as usize;
+    sorted[index]
+}
+
+/// Sample standard deviation.
+fn std_dev(samples: &[Duration], mean: Duration) -> Duration {
+    if samples.len() < 2 {
+        return Duration::ZERO;
+    }
+
+    let mean = mean.as_nanos() as f64;
+    let variance = samples
+        .iter()
+        .map(|x| (x.as_nanos() as f64 - mean).powi(2))
+        .sum::<f64>()
+        / (samples.len() - 1) as f64;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Duration::from_nanos(variance.sqrt() as u64)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&BenchStats> for JsonValue {
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        value.write_json(&mut map);
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        BenchStats::read_json(json)
+    }
+}
+
+fn nanos_to_json(duration: Duration) -> JsonValue {
+    JsonValue::Number(duration.as_nanos() as f64)
+}
+
+fn nanos_from_json(json: &HashMap<String, JsonValue>, key: &str) -> Result<Duration, String> {
+    json.get(key)
+        .and_then(|v| v.get::<f64>().copied())
+        .map(|nanos| Duration::from_nanos(nanos as u64))
+        .ok_or_else(|| format!("Expected stats.{key} to be a number."))
+}
+
+fn count_from_json(json: &HashMap<String, JsonValue>, key: &str) -> Result<u128, String> {
+    json.get(key)
+        .and_then(|v| v.get::<f64>().copied())
+        .map(|count| count as u128)
+        .ok_or_else(|| format!("Expected stats.{key} to be a number."))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::BenchStats;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    #[test]
+    fn summarizes_single_sample() {
+        let stats = BenchStats::from_samples(&nanos(&[100]));
+        assert_eq!(stats.mean, Duration::from_nanos(100));
+        assert_eq!(stats.median, Duration::from_nanos(100));
+        assert_eq!(stats.p5, Duration::from_nanos(100));
+        assert_eq!(stats.p95, Duration::from_nanos(100));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn summarizes_samples() {
+        let stats = BenchStats::from_samples(&nanos(&[50, 10, 40, 20, 30]));
+        assert_eq!(stats.mean, Duration::from_nanos(30));
+        assert_eq!(stats.median, Duration::from_nanos(30));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(50));
+        assert_eq!(stats.p5, Duration::from_nanos(10));
+        assert_eq!(stats.p95, Duration::from_nanos(50));
+        // sqrt(1000 / 4)
+        assert_eq!(stats.std_dev.as_nanos(), 15);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 11, 12, 10, 11, 12, 10, 11, 5000]));
+        assert_eq!(stats.samples, 9);
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.max, Duration::from_nanos(12));
+        assert_eq!(stats.mean, Duration::from_nanos(10));
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let stats = BenchStats::from_samples(&nanos(&[50, 10, 40, 20, 30, 5000]));
+        let json = tinyjson::JsonValue::from(&stats);
+        assert_eq!(BenchStats::try_from(&json).unwrap(), stats);
+    }
+}
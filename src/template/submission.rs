@@ -0,0 +1,227 @@
+/// Module that interprets the response to an answer submission.
+use std::time::Duration;
+
+use crate::template::{ANSI_GREEN, ANSI_ITALIC, ANSI_RED, ANSI_RESET};
+
+/// Outcome of submitting an answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// The answer was wrong. `wait` is the lockout before the next submission, if given.
+    Incorrect {
+        hint: Option<Hint>,
+        wait: Option<Duration>,
+    },
+    /// The part has been solved already, or is not unlocked yet.
+    AlreadySolved,
+    /// An answer was submitted too recently.
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    /// The response could not be interpreted.
+    Unknown,
+}
+
+/// Whether a wrong answer was too high or too low.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Verdict {
+    /// Classify the text of a submission response.
+    pub fn from_response(response: &str) -> Self {
+        let text = normalize(response);
+
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("That's not the right answer") {
+            let hint = if text.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if text.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            Verdict::Incorrect {
+                hint,
+                wait: parse_wait(&text),
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Verdict::RateLimited {
+                wait: parse_wait(&text),
+            }
+        } else if text.contains("Did you already complete it?") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// A one-line summary of the verdict.
+    pub fn summary(&self) -> String {
+        match self {
+            Verdict::Correct => format!("{ANSI_GREEN}✔ That's the right answer!{ANSI_RESET}"),
+            Verdict::Incorrect { hint, wait } => {
+                let hint = match hint {
+                    Some(Hint::TooHigh) => ", it is too high",
+                    Some(Hint::TooLow) => ", it is too low",
+                    None => "",
+                };
+                format!(
+                    "{ANSI_RED}✖ That's not the right answer{hint}.{ANSI_RESET}{}",
+                    format_wait(*wait)
+                )
+            }
+            Verdict::AlreadySolved => {
+                "This part has already been solved, or is not unlocked yet.".into()
+            }
+            Verdict::RateLimited { wait } => {
+                format!(
+                    "An answer was submitted too recently.{}",
+                    format_wait(*wait)
+                )
+            }
+            Verdict::Unknown => "Could not interpret the response.".into(),
+        }
+    }
+}
+
+fn format_wait(wait: Option<Duration>) -> String {
+    wait.map_or_else(String::new, |wait| {
+        format!(" {ANSI_ITALIC}Wait {wait:?} before trying again.{ANSI_RESET}")
+    })
+}
+
+/// Collapse whitespace and strip ANSI escape codes, responses may be wrapped or colored.
+fn normalize(response: &str) -> String {
+    let mut stripped = String::with_capacity(response.len());
+    let mut chars = response.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // skip until the final byte of the escape sequence.
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            stripped.push(c);
+        }
+    }
+
+    stripped.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parse lockouts like `You have 1m 12s left to wait.` or `Please wait 5 minutes before trying again.`
+fn parse_wait(text: &str) -> Option<Duration> {
+    if let Some(start) = text.find("You have ") {
+        let rest = &text[start + "You have ".len()..];
+        let end = rest.find(" left to wait")?;
+        return rest[..end]
+            .split_whitespace()
+            .map(parse_wait_component)
+            .sum::<Option<u64>>()
+            .map(Duration::from_secs);
+    }
+
+    let text = text.to_lowercase();
+    let start = text.find("please wait ")?;
+    let mut words = text[start + "please wait ".len()..].split_whitespace();
+    let amount = match words.next()? {
+        "one" => 1,
+        x => x.parse().ok()?,
+    };
+    let factor = match words.next()? {
+        unit if unit.starts_with("second") => 1,
+        unit if unit.starts_with("minute") => 60,
+        unit if unit.starts_with("hour") => 3600,
+        _ => return None,
+    };
+
+    Some(Duration::from_secs(amount * factor))
+}
+
+/// Parse a single component of a lockout, e.g. `1m` or `12s`.
+fn parse_wait_component(s: &str) -> Option<u64> {
+    let (amount, unit) = s.split_at(s.find(|c: char| !c.is_ascii_digit())?);
+    let amount: u64 = amount.parse().ok()?;
+    match unit {
+        "s" => Some(amount),
+        "m" => Some(amount * 60),
+        "h" => Some(amount * 3600),
+        _ => None,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Hint, Verdict};
+
+    #[test]
+    fn classifies_correct_answers() {
+        let response = "That's the right answer! You are one gold star closer to decorating the North Pole. [Continue to Part Two]";
+        assert_eq!(Verdict::from_response(response), Verdict::Correct);
+    }
+
+    #[test]
+    fn classifies_wrong_answers_with_hints() {
+        let response = "That's not the right answer; your answer is too\nhigh. If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. [Return to Day 1]";
+        assert_eq!(
+            Verdict::from_response(response),
+            Verdict::Incorrect {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60)),
+            }
+        );
+
+        let response = "That's not the right answer; your answer is too low. Because you have guessed incorrectly 5 times on this puzzle, please wait 5 minutes before trying again.";
+        assert_eq!(
+            Verdict::from_response(response),
+            Verdict::Incorrect {
+                hint: Some(Hint::TooLow),
+                wait: Some(Duration::from_secs(300)),
+            }
+        );
+    }
+
+    #[test]
+    fn classifies_wrong_answers_without_hints() {
+        let response = "\x1b[1mThat's not the right answer.\x1b[0m If you're stuck, there are some general tips on the about page.";
+        assert_eq!(
+            Verdict::from_response(response),
+            Verdict::Incorrect {
+                hint: None,
+                wait: None,
+            }
+        );
+    }
+
+    #[test]
+    fn classifies_rate_limits() {
+        let response = "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 12s left to wait. [Return to Day 1]";
+        assert_eq!(
+            Verdict::from_response(response),
+            Verdict::RateLimited {
+                wait: Some(Duration::from_secs(72)),
+            }
+        );
+    }
+
+    #[test]
+    fn classifies_solved_parts() {
+        let response = "You don't seem to be solving the right level. Did you already complete it? [Return to Day 1]";
+        assert_eq!(Verdict::from_response(response), Verdict::AlreadySolved);
+    }
+
+    #[test]
+    fn classifies_unknown_responses() {
+        assert_eq!(Verdict::from_response(""), Verdict::Unknown);
+    }
+}
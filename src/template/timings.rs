@@ -1,19 +1,101 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fs, io::Error, str::FromStr, time::Duration};
 use tinyjson::JsonValue;
 
 use crate::template::Day;
+use crate::template::budget::BenchBudget;
+use crate::template::stats::BenchStats;
 
 static TIMINGS_FILE_PATH: &str = "./data/timings.json";
 
+/// Version of the JSON schema written by [`Timings::store_file`].
+/// Files without a version are from before per-part durations were stored as numbers.
+pub const SCHEMA_VERSION: u8 = 2;
+
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    /// The shared parser of the day, if it registers one with [`crate::solution`].
+    /// The durations of the parts do not include parsing then.
+    pub parse: Option<PartTiming>,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
     pub total_nanos: f64,
 }
 
+impl Timing {
+    /// A timing of a day without any benched parts.
+    pub fn empty(day: Day) -> Self {
+        Self {
+            day,
+            parse: None,
+            part_1: None,
+            part_2: None,
+            total_nanos: 0_f64,
+        }
+    }
+
+    /// Keep the stored timings of parts that were skipped because an earlier part timed out.
+    fn keep_skipped_parts(&mut self, stored: &Timing) {
+        if self.parse.is_some_and(|x| x.timed_out) {
+            self.part_1 = self.part_1.or(stored.part_1);
+            self.part_2 = self.part_2.or(stored.part_2);
+        } else if self.part_1.is_some_and(|x| x.timed_out) {
+            self.part_2 = self.part_2.or(stored.part_2);
+        }
+        self.update_total();
+    }
+
+    /// Recompute the total duration from the measured parts.
+    fn update_total(&mut self) {
+        self.total_nanos = [self.parse, self.part_1, self.part_2]
+            .into_iter()
+            .flatten()
+            .filter(PartTiming::is_measured)
+            .map(|x| x.nanos)
+            .sum();
+    }
+}
+
+/// Represents the benchmark time of a single part.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PartTiming {
+    /// Average duration in nanoseconds.
+    pub nanos: f64,
+    /// Number of samples, unknown for timings migrated from schema version 1.
+    pub samples: Option<u128>,
+    pub stats: Option<BenchStats>,
+    /// The part exceeded its timeout, `nanos` holds the timeout.
+    pub timed_out: bool,
+    /// The part returned an error instead of an answer.
+    pub failed: bool,
+    /// The budget the part was benched with, unknown for timings stored before it was configurable.
+    pub budget: Option<BenchBudget>,
+}
+
+impl PartTiming {
+    pub fn from_stats(stats: BenchStats) -> Self {
+        Self {
+            nanos: stats.mean.as_nanos() as f64,
+            samples: Some(stats.samples),
+            stats: Some(stats),
+            timed_out: false,
+            failed: false,
+            budget: None,
+        }
+    }
+
+    /// Whether the duration of the part is meaningful, i.e. it neither timed out nor failed.
+    pub fn is_measured(&self) -> bool {
+        !self.timed_out && !self.failed
+    }
+
+    pub fn duration(&self) -> Duration {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Duration::from_nanos(self.nanos as u64)
+    }
+}
+
 /// Represents benchmark times for a set of days.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
@@ -29,7 +111,8 @@ impl Timings {
         json.format_to(&mut file)
     }
 
-    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
+    /// Rehydrate timings from a JSON file, migrating older schema versions.
+    /// If not present, returns empty timings.
     pub fn read_from_file() -> Self {
         fs::read_to_string(TIMINGS_FILE_PATH)
             .map_err(|x| x.to_string())
@@ -38,11 +121,16 @@ impl Timings {
     }
 
     /// Merge two sets of timings, overwriting `self` with `other` if present.
+    /// Parts of `other` that were skipped after a timeout keep their stored timing.
     pub fn merge(&self, new: &Self) -> Self {
         let mut data: Vec<Timing> = vec![];
 
         for timing in &new.data {
-            data.push(timing.clone());
+            let mut timing = timing.clone();
+            if let Some(stored) = self.data.iter().find(|t| t.day == timing.day) {
+                timing.keep_skipped_parts(stored);
+            }
+            data.push(timing);
         }
 
         for timing in &self.data {
@@ -51,19 +139,49 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
+    /// Merge the timings of a single part into `self`, keeping the stored timing of the other part.
+    /// The timing of the parser is taken from `new`, as it runs with either part.
+    pub fn merge_part(&self, new: &Self, part: u8) -> Self {
+        let data = new
+            .data
+            .iter()
+            .map(|timing| {
+                let mut merged = self
+                    .data
+                    .iter()
+                    .find(|t| t.day == timing.day)
+                    .cloned()
+                    .unwrap_or_else(|| Timing::empty(timing.day));
+
+                match part {
+                    1 => merged.part_1 = timing.part_1,
+                    2 => merged.part_2 = timing.part_2,
+                    _ => {}
+                }
+                merged.parse = timing.parse;
+                merged.update_total();
+                merged
+            })
+            .collect();
+
+        self.merge(&Timings { data })
+    }
+
     /// Sum up total duration of timings as millis.
     pub fn total_millis(&self) -> f64 {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
+    /// A day is complete if both parts have been benched without timing out or failing.
     pub fn is_day_complete(&self, day: Day) -> bool {
+        let is_part_complete = |part: Option<PartTiming>| part.is_some_and(|x| x.is_measured());
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.day == day && is_part_complete(t.part_1) && is_part_complete(t.part_2))
     }
 }
 
@@ -73,6 +191,11 @@ impl From<Timings> for JsonValue {
     fn from(value: Timings) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert(
+            "version".into(),
+            JsonValue::Number(f64::from(SCHEMA_VERSION)),
+        );
+
         map.insert(
             "data".into(),
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
@@ -88,20 +211,37 @@ impl TryFrom<String> for Timings {
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
 
-        let json_data = json
+        let json = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        let version = match json.get("version") {
+            None => 1,
+            Some(v) => v
+                .get::<f64>()
+                .map(|x| *x as u8)
+                .ok_or("expected `json.version` to be a number.")?,
+        };
+
+        let json_data = json
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
             .ok_or("expected `json.data` to be an array.")?;
 
-        Ok(Timings {
-            data: json_data
+        let data = match version {
+            1 => json_data
+                .iter()
+                .map(migrate_v1_timing)
+                .collect::<Result<_, _>>()?,
+            SCHEMA_VERSION => json_data
                 .iter()
                 .map(Timing::try_from)
                 .collect::<Result<_, _>>()?,
-        })
+            v => return Err(format!("unsupported timings schema version {v}.")),
+        };
+
+        Ok(Timings { data })
     }
 }
 
@@ -114,23 +254,28 @@ impl From<&Timing> for JsonValue {
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
+        map.insert(
+            "parse".into(),
+            value
+                .parse
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
 
         map.insert(
             "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            value
+                .part_1
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
         );
 
         map.insert(
             "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            value
+                .part_2
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
         );
 
         JsonValue::Object(map)
@@ -145,62 +290,252 @@ impl TryFrom<&JsonValue> for Timing {
             .get::<HashMap<String, JsonValue>>()
             .ok_or("Expected timing to be a JSON object.")?;
 
-        let day = json
-            .get("day")
-            .and_then(|v| v.get::<String>())
-            .and_then(|day| Day::from_str(day).ok())
-            .ok_or("Expected timing.day to be a Day struct.")?;
+        let day = day_from_json(json)?;
+
+        // timings stored before parsers were timed separately have no parse timing.
+        let parse = match json.get("parse") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(PartTiming::try_from(v)?),
+        };
 
         let part_1 = json
             .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+            .map(|v| {
+                if v.is_null() {
+                    Ok(None)
+                } else {
+                    PartTiming::try_from(v).map(Some)
+                }
+            })
+            .ok_or("Expected timing.part_1 to be null or object.")??;
 
         let part_2 = json
             .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
-
-        let total_nanos = json
-            .get("total_nanos")
-            .and_then(|v| v.get::<f64>().copied())
-            .ok_or("Expected timing.total_nanos to be a number.")?;
+            .map(|v| {
+                if v.is_null() {
+                    Ok(None)
+                } else {
+                    PartTiming::try_from(v).map(Some)
+                }
+            })
+            .ok_or("Expected timing.part_2 to be null or object.")??;
 
         Ok(Timing {
             day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
-            total_nanos,
+            parse,
+            part_1,
+            part_2,
+            total_nanos: total_nanos_from_json(json)?,
+        })
+    }
+}
+
+fn day_from_json(json: &HashMap<String, JsonValue>) -> Result<Day, String> {
+    json.get("day")
+        .and_then(|v| v.get::<String>())
+        .and_then(|day| Day::parse_stored(day).ok())
+        .ok_or_else(|| "Expected timing.day to be a Day struct.".into())
+}
+
+fn total_nanos_from_json(json: &HashMap<String, JsonValue>) -> Result<f64, String> {
+    json.get("total_nanos")
+        .and_then(|v| v.get::<f64>().copied())
+        .ok_or_else(|| "Expected timing.total_nanos to be a number.".into())
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartTiming> for JsonValue {
+    fn from(value: &PartTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+        map.insert(
+            "samples".into(),
+            value
+                .samples
+                .map_or(JsonValue::Null, |x| JsonValue::Number(x as f64)),
+        );
+        map.insert(
+            "stats".into(),
+            value
+                .stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+        map.insert("timed_out".into(), JsonValue::Boolean(value.timed_out));
+        map.insert("failed".into(), JsonValue::Boolean(value.failed));
+        map.insert(
+            "budget".into(),
+            value
+                .budget
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartTiming {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part timing to be a JSON object.")?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected part.nanos to be a number.")?;
+
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u128);
+
+        // timings stored before timeouts were supported did not time out.
+        let timed_out = json
+            .get("timed_out")
+            .and_then(|v| v.get::<bool>())
+            .copied()
+            .unwrap_or(false);
+
+        // neither did timings stored before parts could fail.
+        let failed = json
+            .get("failed")
+            .and_then(|v| v.get::<bool>())
+            .copied()
+            .unwrap_or(false);
+
+        let budget = match json.get("budget") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(BenchBudget::try_from(v)?),
+        };
+
+        Ok(PartTiming {
+            nanos,
+            samples,
+            stats: stats_from_json(json, "stats")?,
+            timed_out,
+            failed,
+            budget,
         })
     }
 }
 
+/// Stats are optional, timings stored before they were recorded do not have them.
+fn stats_from_json(
+    json: &HashMap<String, JsonValue>,
+    key: &str,
+) -> Result<Option<BenchStats>, String> {
+    match json.get(key) {
+        None => Ok(None),
+        Some(v) if v.is_null() => Ok(None),
+        Some(v) => BenchStats::try_from(v).map(Some),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Read a timing of schema version 1, which stored parts as formatted durations like `"74.13ms"`.
+fn migrate_v1_timing(value: &JsonValue) -> Result<Timing, String> {
+    let json = value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("Expected timing to be a JSON object.")?;
+
+    let migrate_part = |key: &str| -> Result<Option<PartTiming>, String> {
+        let part = json
+            .get(key)
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or_else(|| format!("Expected timing.{key} to be null or string."))?;
+
+        let Some(part) = part else {
+            return Ok(None);
+        };
+
+        let nanos = parse_duration_str(part)
+            .ok_or_else(|| format!("Expected timing.{key} to be a duration."))?;
+
+        let stats = stats_from_json(json, &format!("{key}_stats"))?;
+
+        Ok(Some(PartTiming {
+            nanos,
+            samples: stats.map(|x| x.samples),
+            stats,
+            timed_out: false,
+            failed: false,
+            budget: None,
+        }))
+    };
+
+    Ok(Timing {
+        day: day_from_json(json)?,
+        parse: None,
+        part_1: migrate_part("part_1")?,
+        part_2: migrate_part("part_2")?,
+        total_nanos: total_nanos_from_json(json)?,
+    })
+}
+
+fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
+    s.split(postfix).next()?.parse().ok()
+}
+
+/// Parse a `Debug`-formatted [`Duration`] to nanoseconds.
+fn parse_duration_str(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    match s.trim() {
+        s if s.contains("ns") => parse_to_float(s, "ns"),
+        s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
+        s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
+        s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 
 #[cfg(feature = "test_lib")]
 mod tests {
     use crate::day;
 
-    use super::{Timing, Timings};
+    use super::{PartTiming, Timing, Timings};
+
+    fn mock_part(nanos: f64) -> PartTiming {
+        PartTiming {
+            nanos,
+            samples: None,
+            stats: None,
+            timed_out: false,
+            failed: false,
+            budget: None,
+        }
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    parse: None,
+                    part_1: Some(mock_part(10_000_000.0)),
+                    part_2: Some(mock_part(20_000_000.0)),
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    parse: None,
+                    part_1: Some(mock_part(30_000_000.0)),
+                    part_2: Some(mock_part(40_000_000.0)),
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
+                    parse: None,
+                    part_1: Some(mock_part(40_000_000.0)),
                     part_2: None,
                     total_nanos: 4e+10,
                 },
@@ -213,14 +548,35 @@ mod tests {
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1": { "nanos": 1000000, "samples": 10, "stats": null }, "part_2": null, "total_nanos": 1000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            let part_1 = timing.part_1.unwrap();
+            assert_eq!(part_1.nanos, 1_000_000_f64);
+            assert_eq!(part_1.samples, Some(10));
+            assert_eq!(part_1.stats, None);
             assert_eq!(timing.part_2, None);
-            assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.total_nanos, 1_000_000_f64);
+        }
+
+        #[test]
+        fn handles_json_timings_with_stats() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1": { "nanos": 1000000, "samples": 10, "stats": { "nanos": 1000000, "samples": 10, "min_nanos": 900000, "max_nanos": 1100000, "median_nanos": 1000000, "p5_nanos": 900000, "p95_nanos": 1100000, "std_dev_nanos": 50000, "outliers": 1 } }, "part_2": null, "total_nanos": 1000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let stats = timings.data[0].part_1.unwrap().stats.unwrap();
+            assert_eq!(stats.median.as_nanos(), 1_000_000);
+            assert_eq!(stats.samples, 10);
+            assert_eq!(stats.outliers, 1);
+        }
+
+        #[test]
+        fn handles_days_outside_the_event() {
+            let json = r#"{ "version": 2, "data": [{ "day": "2025_20", "part_1": null, "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].day.into_inner(), 20);
+            assert_eq!(timings.data[0].day.is_in_event(), false);
         }
 
         #[test]
@@ -243,10 +599,54 @@ mod tests {
             let json = r#"{ "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null, "total_nanos": 0 }] }"#.to_string();
             Timings::try_from(json).unwrap();
         }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_unsupported_versions() {
+            let json = r#"{ "version": 99, "data": [] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+    }
+
+    mod migration {
+        use crate::{day, template::timings::Timings};
+
+        #[test]
+        fn migrates_formatted_durations() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000 }, { "day": "02", "part_1": "74.13ns", "part_2": "1.5s", "total_nanos": 1500000074.13 }, { "day": "03", "part_1": "2.5µs", "part_2": "100.0ms", "total_nanos": 100002500 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data.len(), 3);
+            assert_eq!(timings.data[0].day, day!(1));
+            assert_eq!(timings.data[0].part_1.unwrap().nanos, 1_000_000_f64);
+            assert_eq!(timings.data[0].part_1.unwrap().samples, None);
+            assert_eq!(timings.data[0].part_2, None);
+            assert_eq!(timings.data[1].part_1.unwrap().nanos, 74.13_f64);
+            assert_eq!(timings.data[1].part_2.unwrap().nanos, 1_500_000_000_f64);
+            assert_eq!(timings.data[2].part_1.unwrap().nanos, 2_500_f64);
+            assert_eq!(timings.data[2].part_2.unwrap().nanos, 100_000_000_f64);
+        }
+
+        #[test]
+        fn migrates_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000, "part_1_stats": { "nanos": 1000000, "samples": 10, "min_nanos": 900000, "max_nanos": 1100000, "median_nanos": 1000000, "p5_nanos": 900000, "p95_nanos": 1100000, "std_dev_nanos": 50000, "outliers": 1 }, "part_2_stats": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let part_1 = timings.data[0].part_1.unwrap();
+            assert_eq!(part_1.samples, Some(10));
+            assert_eq!(part_1.stats.unwrap().median.as_nanos(), 1_000_000);
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_malformed_durations() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "fast", "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
     }
 
     mod serialization {
-        use super::get_mock_timings;
+        use super::{get_mock_timings, mock_part};
+        use crate::template::budget::BenchBudget;
+        use crate::template::timings::{PartTiming, Timings};
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
@@ -266,26 +666,55 @@ mod tests {
                 3
             );
         }
+
+        #[test]
+        fn roundtrips_timings() {
+            let timings = get_mock_timings();
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data.len(), 3);
+            assert_eq!(parsed.data[0].part_1, timings.data[0].part_1);
+            assert_eq!(parsed.data[2].part_2, None);
+        }
+
+        #[test]
+        fn roundtrips_budgets() {
+            let mut timings = get_mock_timings();
+            timings.data[0].part_1 = Some(PartTiming {
+                budget: Some(BenchBudget::QUICK),
+                ..mock_part(1_000.0)
+            });
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(
+                parsed.data[0].part_1.unwrap().budget,
+                Some(BenchBudget::QUICK)
+            );
+            assert_eq!(parsed.data[0].part_2.unwrap().budget, None);
+        }
     }
 
     mod is_day_complete {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::timings::{PartTiming, Timing, Timings},
         };
 
+        use super::mock_part;
+
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
+                    parse: None,
+                    part_1: Some(mock_part(1_000_000.0)),
+                    part_2: Some(mock_part(2_000_000.0)),
                     total_nanos: 3_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -293,13 +722,32 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
+                    parse: None,
+                    part_1: Some(mock_part(1_000_000.0)),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+
+        #[test]
+        fn handles_failed_days() {
+            let timings = Timings {
+                data: vec![Timing {
+                    day: day!(1),
+                    parse: None,
+                    part_1: Some(mock_part(1_000_000.0)),
+                    part_2: Some(PartTiming {
+                        failed: true,
+                        ..mock_part(2_000_000.0)
+                    }),
+                    total_nanos: 1_000_000_000_f64,
+                }],
+            };
+
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -307,23 +755,24 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
     mod merge {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::timings::{PartTiming, Timing, Timings},
         };
 
-        use super::get_mock_timings;
+        use super::{get_mock_timings, mock_part};
 
         #[test]
         fn handles_disjunct_timings() {
@@ -331,6 +780,7 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(3),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
@@ -351,6 +801,7 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(2),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
@@ -365,6 +816,30 @@ mod tests {
             assert_eq!(merged.data[2].day, day!(4));
         }
 
+        #[test]
+        fn keeps_parts_skipped_after_a_timeout() {
+            let timings = get_mock_timings();
+
+            let other = Timings {
+                data: vec![Timing {
+                    day: day!(2),
+                    parse: None,
+                    part_1: Some(PartTiming {
+                        timed_out: true,
+                        ..mock_part(50_000_000.0)
+                    }),
+                    part_2: None,
+                    total_nanos: 0_f64,
+                }],
+            };
+            let merged = timings.merge(&other);
+
+            assert_eq!(merged.data.len(), 3);
+            assert!(merged.data[1].part_1.unwrap().timed_out);
+            assert_eq!(merged.data[1].part_2, Some(mock_part(40_000_000.0)));
+            assert_eq!(merged.data[1].total_nanos, 40_000_000.0);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let timings = Timings::default();
@@ -373,6 +848,58 @@ mod tests {
             assert_eq!(merged.data.len(), 3);
         }
 
+        #[test]
+        fn handles_single_parts() {
+            let timings = get_mock_timings();
+
+            let other = Timings {
+                data: vec![
+                    Timing {
+                        day: day!(1),
+                        parse: None,
+                        part_1: None,
+                        part_2: Some(mock_part(5_000_000.0)),
+                        total_nanos: 5_000_000_f64,
+                    },
+                    Timing {
+                        day: day!(3),
+                        parse: None,
+                        part_1: None,
+                        part_2: Some(mock_part(1_000_000.0)),
+                        total_nanos: 1_000_000_f64,
+                    },
+                ],
+            };
+            let merged = timings.merge_part(&other, 2);
+
+            assert_eq!(merged.data.len(), 4);
+            assert_eq!(merged.data[0].part_1, Some(mock_part(10_000_000.0)));
+            assert_eq!(merged.data[0].part_2, Some(mock_part(5_000_000.0)));
+            assert_eq!(merged.data[0].total_nanos, 15_000_000_f64);
+            assert_eq!(merged.data[2].day, day!(3));
+            assert_eq!(merged.data[2].part_1, None);
+        }
+
+        #[test]
+        fn handles_single_parts_with_parse_times() {
+            let timings = get_mock_timings();
+
+            let other = Timings {
+                data: vec![Timing {
+                    day: day!(1),
+                    parse: Some(mock_part(1_000_000.0)),
+                    part_1: Some(mock_part(2_000_000.0)),
+                    part_2: None,
+                    total_nanos: 3_000_000_f64,
+                }],
+            };
+            let merged = timings.merge_part(&other, 1);
+
+            assert_eq!(merged.data[0].parse, Some(mock_part(1_000_000.0)));
+            assert_eq!(merged.data[0].part_2, Some(mock_part(20_000_000.0)));
+            assert_eq!(merged.data[0].total_nanos, 23_000_000_f64);
+        }
+
         #[test]
         fn handles_empty_other_timings() {
             let timings = get_mock_timings();
@@ -0,0 +1,101 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The first year Advent of Code took place.
+const FIRST_YEAR: u16 = 2015;
+
+/// The first year Advent of Code had 12 instead of 25 puzzles.
+const FIRST_SHORT_YEAR: u16 = 2025;
+
+/// A year of Advent of Code (i.e. an integer from 2015 on).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2024).unwrap();
+/// assert_eq!(year.to_string(), "2024")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if Advent of Code took place in it,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// The last day of the event, Advent of Code has 12 puzzles from 2025 on.
+    pub fn last_day(self) -> u8 {
+        if self.0 >= FIRST_SHORT_YEAR { 12 } else { 25 }
+    }
+
+    /// The year configured with the `AOC_YEAR` environment variable, used for days without a year.
+    pub fn from_env() -> Option<Self> {
+        std::env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    /// Drops the year if it is `AOC_YEAR`, as days of that year are stored without a year,
+    /// e.g. in `src/bin/05.rs`.
+    pub fn normalize(year: Option<Self>) -> Option<Self> {
+        Self::normalize_for(year, Self::from_env())
+    }
+
+    /// Drops the year if it is `configured`, see [`Year::normalize`].
+    pub(crate) fn normalize_for(year: Option<Self>, configured: Option<Self>) -> Option<Self> {
+        year.filter(|x| Some(*x) != configured)
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting a year from {FIRST_YEAR} on")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {
+        const {
+            $crate::template::Year::new($year)
+                .expect("invalid year, expecting a value from 2015 on")
+        }
+    };
+}